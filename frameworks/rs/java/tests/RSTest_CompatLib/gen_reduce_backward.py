@@ -0,0 +1,244 @@
+#!/usr/bin/env python
+#
+# Copyright (C) 2016 The Android Open Source Project
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#      http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+
+"""Generates the backward-reference reduce test from the forward one.
+
+reduce.rs places every "#pragma rs reduce" before the functions it names
+(forward reference); reduce_backward.rs must contain the same kernels with
+each pragma moved after the last function it names (backward reference).
+A function defined in an included header (or in a header that one
+includes) counts as defined at the #include line, which is how both
+files share reduce_common.rsh.
+Likewise UT_reduce_backward.java is UT_reduce.java driving the other
+script.  Edit only reduce.rs and UT_reduce.java, then run this script.
+
+reduce.rs keeps its functions in more than one header, and defines a
+few outconverters itself after the #includes.  That way
+reduce_backward.rs places some pragmas between two #includes and some
+between two function definitions, rather than all of them after a single
+#include.  Keep that layout when adding kernels.
+
+Usage: gen_reduce_backward.py [--check]
+
+With --check, nothing is written; the exit status is nonzero if either
+generated file is out of date.
+"""
+
+import os
+import re
+import sys
+
+SRC_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)),
+                       'src', 'com', 'android', 'rs', 'test')
+
+RS_FORWARD_COMMENT = '''\
+// Same as reduce_backward.rs, except this test case places the
+// pragmas before the functions (forward reference), and the other
+// test case places the pragmas after the functions (backward
+// reference).  The functions themselves are in the included headers,
+// except for the outconverters at the end of this file, which no other
+// script uses.
+//
+// reduce_backward.rs is generated from this file by
+// gen_reduce_backward.py; rerun it after changing this file.
+'''
+
+RS_BACKWARD_COMMENT = '''\
+// Same as reduce.rs, except this test case places the pragmas after
+// the functions (backward reference), and the other test case places
+// the pragmas before the functions (forward reference).  The functions
+// themselves are in the included headers, except for the outconverters
+// defined below, which no other script uses.
+//
+// This file is generated from reduce.rs by gen_reduce_backward.py.
+// Do not edit it by hand.
+'''
+
+JAVA_FORWARD_COMMENT = '''\
+/* Same as UT_reduce_backward.java, except this test case exercises
+ * pragmas before the functions (forward reference), and the other
+ * test case exercises the pragmas after the functions (backward
+ * reference).
+ *
+ * UT_reduce_backward.java is generated from this file by
+ * gen_reduce_backward.py; rerun it after changing this file.
+ */
+'''
+
+JAVA_BACKWARD_COMMENT = '''\
+/* Same as UT_reduce.java, except this test case exercises
+ * pragmas after the functions (backward reference), and the other
+ * test case exercises the pragmas before the functions (forward
+ * reference).
+ *
+ * This file is generated from UT_reduce.java by gen_reduce_backward.py.
+ * Do not edit it by hand.
+ */
+'''
+
+PRAGMA_RE = re.compile(r'^#pragma\s+rs\s+reduce\s*\(')
+PRAGMA_FUNC_RE = re.compile(
+    r'\b(?:initializer|accumulator|combiner|outconverter|halter)\s*\(\s*(\w+)\s*\)')
+
+
+def fail(msg):
+    sys.stderr.write('gen_reduce_backward.py: error: %s\n' % msg)
+    sys.exit(1)
+
+
+def replace_once(text, old, new, filename):
+    if text.count(old) != 1:
+        fail('expected exactly one copy of the header comment in %s' % filename)
+    return text.replace(old, new)
+
+
+def extract_pragmas(lines):
+    """Removes every reduce pragma (and the blank line that follows it).
+
+    Returns the remaining lines and a list of pragmas, each a list of lines.
+    """
+    body = []
+    pragmas = []
+    i = 0
+    while i < len(lines):
+        if not PRAGMA_RE.match(lines[i]):
+            body.append(lines[i])
+            i += 1
+            continue
+        pragma = [lines[i]]
+        while pragma[-1].rstrip().endswith('\\'):
+            i += 1
+            if i == len(lines):
+                fail('unterminated pragma: %s' % pragma[0].strip())
+            pragma.append(lines[i])
+        i += 1
+        if i < len(lines) and lines[i].strip() == '':
+            i += 1
+        pragmas.append(pragma)
+    return body, pragmas
+
+
+INCLUDE_RE = re.compile(r'^#include\s+"([^"]+)"')
+
+
+def find_definition_end(lines, func):
+    """Returns the index of the last line of the definition of func.
+
+    If func is defined in a header included by lines (possibly through
+    other headers) rather than in lines itself, returns the index of that
+    #include.
+    """
+    end = find_local_definition_end(lines, func)
+    if end is not None:
+        return end
+    for index, line in enumerate(lines):
+        match = INCLUDE_RE.match(line)
+        if match and header_defines(match.group(1), func):
+            return index
+    fail('no definition found for function %s' % func)
+
+
+def header_defines(header, func):
+    """Returns whether header, or a header it includes, defines func."""
+    with open(os.path.join(SRC_DIR, header)) as f:
+        lines = f.read().splitlines(True)
+    if find_local_definition_end(lines, func) is not None:
+        return True
+    for line in lines:
+        match = INCLUDE_RE.match(line)
+        if match and header_defines(match.group(1), func):
+            return True
+    return False
+
+
+def find_local_definition_end(lines, func):
+    decl_re = re.compile(r'^[A-Za-z_].*\b%s\s*\(' % re.escape(func))
+    for start, line in enumerate(lines):
+        if not decl_re.match(line):
+            continue
+        depth = 0
+        seen_body = False
+        for end in range(start, len(lines)):
+            if not seen_body and lines[end].rstrip().endswith(';'):
+                break  # a prototype, not the definition
+            depth += lines[end].count('{') - lines[end].count('}')
+            seen_body = seen_body or '{' in lines[end]
+            if seen_body and depth == 0:
+                return end
+    return None
+
+
+def generate_rs(forward):
+    text = replace_once(forward, RS_FORWARD_COMMENT, RS_BACKWARD_COMMENT,
+                        'reduce.rs')
+    body, pragmas = extract_pragmas(text.splitlines(True))
+
+    # Pragmas are inserted bottom-up so that earlier indices stay valid;
+    # pragmas that share an insertion point keep their original order.
+    placed = []
+    for order, pragma in enumerate(pragmas):
+        funcs = PRAGMA_FUNC_RE.findall(''.join(pragma))
+        if not funcs:
+            fail('pragma names no functions: %s' % pragma[0].strip())
+        end = max(find_definition_end(body, f) for f in funcs)
+        placed.append((end, order, pragma))
+    for end, order, pragma in sorted(placed, reverse=True):
+        body[end + 1:end + 1] = ['\n'] + pragma
+    return ''.join(body)
+
+
+def generate_java(forward):
+    text = re.sub(r'\bUT_reduce\b', 'UT_reduce_backward', forward)
+    text = re.sub(r'\bScriptC_reduce\b', 'ScriptC_reduce_backward', text)
+    text = text.replace('"reduce"', '"reduce_backward"')
+    # The header comment is swapped last so that the renames above do not
+    # touch the (already correct) names mentioned in it.
+    return replace_once(text, JAVA_FORWARD_COMMENT, JAVA_BACKWARD_COMMENT,
+                        'UT_reduce.java')
+
+
+def main(argv):
+    check = False
+    for arg in argv[1:]:
+        if arg == '--check':
+            check = True
+        else:
+            sys.stderr.write(__doc__)
+            return 2
+
+    outputs = [
+        ('reduce.rs', 'reduce_backward.rs', generate_rs),
+        ('UT_reduce.java', 'UT_reduce_backward.java', generate_java),
+    ]
+    stale = False
+    for src, dst, generate in outputs:
+        with open(os.path.join(SRC_DIR, src)) as f:
+            generated = generate(f.read())
+        dst_path = os.path.join(SRC_DIR, dst)
+        if check:
+            with open(dst_path) as f:
+                if f.read() != generated:
+                    sys.stderr.write('%s is out of date with %s\n' % (dst, src))
+                    stale = True
+        else:
+            with open(dst_path, 'w') as f:
+                f.write(generated)
+    return 1 if stale else 0
+
+
+if __name__ == '__main__':
+    sys.exit(main(sys.argv))
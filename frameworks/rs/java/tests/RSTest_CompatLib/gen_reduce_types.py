@@ -0,0 +1,583 @@
+#!/usr/bin/env python
+#
+# Copyright (C) 2016 The Android Open Source Project
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#      http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+
+"""Generates the reduce type-matrix test.
+
+For every scalar and vector (2, 3, 4) element type in TYPES below, this
+stamps out four reduction kernels in reduce_types.rs
+
+    sum_<T>   component-wise sum (wrapping for integer types; not generated
+              for half, whose sums would round)
+    min_<T>   component-wise minimum
+    max_<T>   component-wise maximum
+    ffz_<T>   lowest index of a cell whose components are all zero
+
+and the Java code in UT_reduce_types.java that launches each of them on a
+random input and checks the result against a Java reference.
+
+Usage: gen_reduce_types.py [--check]
+
+With --check, nothing is written; the exit status is nonzero if either
+generated file is out of date.
+"""
+
+import os
+import sys
+
+SRC_DIR = os.path.join(os.path.dirname(os.path.abspath(__file__)),
+                       'src', 'com', 'android', 'rs', 'test')
+
+# Cells per input allocation.  Prime, so that the input doesn't split
+# evenly across worker threads.
+INPUT_LEN = 10007
+
+
+def largest_safe_limit(bits):
+    """Largest power of two limit such that a sum of INPUT_LEN values in
+    [-limit, limit) cannot overflow a signed integer of the given width."""
+    limit = 1
+    while INPUT_LEN * (limit << 1) <= (1 << (bits - 1)) - 1:
+        limit <<= 1
+    return limit
+
+
+# (rs type, Java element prefix, kind, bits, Java array type, limit on the
+# input magnitude or None for the full range)
+#
+# Signed 32- and 64-bit inputs are limited so that sums cannot overflow,
+# which would be undefined behavior in the script.  Narrower signed sums
+# wrap through the (well-defined in RenderScript) narrowing conversion.
+#
+# half inputs are the same small integers as float inputs, which half
+# represents exactly, so the Java side needs no half arithmetic: it
+# computes min and max on the values and compares raw half bits.  No
+# other test in this suite uses F16 allocations, so the half cases only
+# run where halfSupported() finds the F16 copy path working; elsewhere
+# they are skipped and logged.
+TYPES = [
+    ('char',   'I8',  'SIGNED',   8,  'byte',   None),
+    ('uchar',  'U8',  'UNSIGNED', 8,  'byte',   None),
+    ('short',  'I16', 'SIGNED',   16, 'short',  None),
+    ('ushort', 'U16', 'UNSIGNED', 16, 'short',  None),
+    ('int',    'I32', 'SIGNED',   32, 'int',    largest_safe_limit(32)),
+    ('uint',   'U32', 'UNSIGNED', 32, 'int',    None),
+    ('long',   'I64', 'SIGNED',   64, 'long',   1 << 20),
+    ('ulong',  'U64', 'UNSIGNED', 64, 'long',   None),
+    ('half',   'F16', 'HALF',     16, 'half',   None),
+    ('float',  'F32', 'FLOAT',    32, 'float',  None),
+    ('double', 'F64', 'FLOAT',    64, 'double', None),
+]
+
+VEC_SIZES = [1, 2, 3, 4]
+
+LIMITS = {
+    'char':   ('-128', '127'),
+    'uchar':  ('0', '255'),
+    'short':  ('-32768', '32767'),
+    'ushort': ('0', '65535'),
+    'int':    ('(-2147483647 - 1)', '2147483647'),
+    'uint':   ('0', '4294967295U'),
+    'long':   ('(-9223372036854775807L - 1)', '9223372036854775807L'),
+    'ulong':  ('0', '18446744073709551615UL'),
+    'half':   ('(half) neginf()', '(half) posinf()'),
+    'float':  ('neginf()', 'posinf()'),
+    'double': ('(double) neginf()', '(double) posinf()'),
+}
+
+RS_HEADER = '''\
+#include "shared.rsh"
+
+// Reductions over every scalar and vector element type.
+//
+// This file is generated by gen_reduce_types.py.  Do not edit it by hand.
+
+// Shared by all ffz_* kernels: the accumulator is the lowest index found
+// so far, or -1.
+static void ffzInit(int *accum) { *accum = -1; }
+
+static void ffzCombine(int *accum, const int *other) {
+  if ((*other >= 0) && ((*accum < 0) || (*other < *accum)))
+    *accum = *other;
+}
+'''
+
+JAVA_HEADER = '''\
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Runs the sum, min, max and ffz reductions of reduce_types.rs for every
+ * scalar and vector element type (no sum for half) and checks them against
+ * Java references.
+ *
+ * This file is generated by gen_reduce_types.py.  Do not edit it by hand.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.os.Build;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Arrays;
+import java.util.Random;
+
+public class UT_reduce_types extends UnitTest {
+    private static final String TAG = "reduce_types";
+
+    // Cells per input allocation.  Prime, so that the input doesn't split
+    // evenly across worker threads.
+    private static final int INPUT_LEN = @INPUT_LEN@;
+
+    private enum Kind { SIGNED, UNSIGNED, FLOAT }
+
+    protected UT_reduce_types(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "reduce_types", ctx);
+    }
+
+    ///////////////////////////////////////////////////////////////////
+    // Inputs
+    ///////////////////////////////////////////////////////////////////
+
+    // Brings v into the range of an integer type, the way a C conversion
+    // to that type would.
+    private static long normalize(long v, int bits, Kind kind) {
+        if (bits == 64)
+            return v;
+        if (kind == Kind.SIGNED)
+            return (v << (64 - bits)) >> (64 - bits);
+        return v & ((1L << bits) - 1);
+    }
+
+    private static long[] randomIntegers(Random rand, int vecSize, int bits, Kind kind, int limit) {
+        long[] array = new long[INPUT_LEN * vecSize];
+        for (int i = 0; i < array.length; ++i) {
+            if (limit == 0)
+                array[i] = normalize(rand.nextLong(), bits, kind);
+            else if (kind == Kind.SIGNED)
+                array[i] = rand.nextInt(2 * limit) - limit;
+            else
+                array[i] = rand.nextInt(limit);
+        }
+        return array;
+    }
+
+    // Small integral values, so that sums are exact in any order and half
+    // represents every value exactly.
+    private static double[] randomFloats(Random rand, int vecSize) {
+        double[] array = new double[INPUT_LEN * vecSize];
+        for (int i = 0; i < array.length; ++i)
+            array[i] = rand.nextInt(2001) - 1000;
+        return array;
+    }
+
+    // Zeroes two random cells, so that ffz has something to find.
+    private static void plantZeros(Random rand, long[] array, int vecSize) {
+        for (int n = 0; n < 2; ++n) {
+            final int cell = rand.nextInt(INPUT_LEN);
+            Arrays.fill(array, cell * vecSize, (cell + 1) * vecSize, 0);
+        }
+    }
+
+    private static void plantZeros(Random rand, double[] array, int vecSize) {
+        for (int n = 0; n < 2; ++n) {
+            final int cell = rand.nextInt(INPUT_LEN);
+            Arrays.fill(array, cell * vecSize, (cell + 1) * vecSize, 0);
+        }
+    }
+
+    private static byte[] toByteArray(long[] in) {
+        byte[] out = new byte[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = (byte) in[i];
+        return out;
+    }
+
+    private static short[] toShortArray(long[] in) {
+        short[] out = new short[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = (short) in[i];
+        return out;
+    }
+
+    private static int[] toIntArray(long[] in) {
+        int[] out = new int[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = (int) in[i];
+        return out;
+    }
+
+    private static long[] toLongArray(long[] in) {
+        return in;
+    }
+
+    // Bits of the half that exactly represents v, an integer with
+    // |v| < 2048.
+    private static short halfBits(double v) {
+        final int iv = (int) v;
+        if (iv == 0)
+            return 0;
+        final int sign = (iv < 0) ? 0x8000 : 0;
+        final int mag = Math.abs(iv);
+        final int exp = 31 - Integer.numberOfLeadingZeros(mag);
+        return (short) (sign | ((exp + 15) << 10) | ((mag << (10 - exp)) & 0x3ff));
+    }
+
+    private static short[] toHalfArray(double[] in) {
+        short[] out = new short[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = halfBits(in[i]);
+        return out;
+    }
+
+    // Raw half bits of each value, as values(short) etc. return them.
+    private static long[] halfBits(double[] in) {
+        long[] out = new long[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = halfBits(in[i]);
+        return out;
+    }
+
+    // Whether this device can run the half cases: RenderScript gained half
+    // in API 23, and an F16 allocation must round-trip raw bits through
+    // the support library's copy path.
+    private static boolean halfSupported(RenderScript RS) {
+        if (Build.VERSION.SDK_INT < Build.VERSION_CODES.M)
+            return false;
+        try {
+            Allocation alloc = Allocation.createSized(RS, Element.F16(RS), 1);
+            final short[] in = { halfBits(1.0) };
+            short[] out = new short[1];
+            alloc.copyFrom(in);
+            alloc.copyTo(out);
+            alloc.destroy();
+            return out[0] == in[0];
+        } catch (RSRuntimeException e) {
+            return false;
+        }
+    }
+
+    private static float[] toFloatArray(double[] in) {
+        float[] out = new float[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = (float) in[i];
+        return out;
+    }
+
+    private static double[] toDoubleArray(double[] in) {
+        return in;
+    }
+
+    private static Allocation createInput(RenderScript RS, Element elem, int vecSize) {
+        Allocation alloc = Allocation.createSized(RS, elem, INPUT_LEN);
+        if (vecSize == 3)
+            alloc.setAutoPadding(true);
+        return alloc;
+    }
+
+    ///////////////////////////////////////////////////////////////////
+    // Java references
+    ///////////////////////////////////////////////////////////////////
+
+    private static boolean less(long a, long b, Kind kind) {
+        if (kind == Kind.UNSIGNED)
+            return (a ^ Long.MIN_VALUE) < (b ^ Long.MIN_VALUE);
+        return a < b;
+    }
+
+    private static long[] sum(long[] in, int vecSize, int bits, Kind kind) {
+        long[] rslt = new long[vecSize];
+        for (int i = 0; i < in.length; ++i)
+            rslt[i % vecSize] += in[i];
+        for (int c = 0; c < vecSize; ++c)
+            rslt[c] = normalize(rslt[c], bits, kind);
+        return rslt;
+    }
+
+    private static long[] min(long[] in, int vecSize, Kind kind) {
+        long[] rslt = Arrays.copyOf(in, vecSize);
+        for (int i = vecSize; i < in.length; ++i)
+            if (less(in[i], rslt[i % vecSize], kind)) rslt[i % vecSize] = in[i];
+        return rslt;
+    }
+
+    private static long[] max(long[] in, int vecSize, Kind kind) {
+        long[] rslt = Arrays.copyOf(in, vecSize);
+        for (int i = vecSize; i < in.length; ++i)
+            if (less(rslt[i % vecSize], in[i], kind)) rslt[i % vecSize] = in[i];
+        return rslt;
+    }
+
+    private static double[] sum(double[] in, int vecSize) {
+        double[] rslt = new double[vecSize];
+        for (int i = 0; i < in.length; ++i)
+            rslt[i % vecSize] += in[i];
+        return rslt;
+    }
+
+    private static double[] min(double[] in, int vecSize) {
+        double[] rslt = Arrays.copyOf(in, vecSize);
+        for (int i = vecSize; i < in.length; ++i)
+            rslt[i % vecSize] = Math.min(rslt[i % vecSize], in[i]);
+        return rslt;
+    }
+
+    private static double[] max(double[] in, int vecSize) {
+        double[] rslt = Arrays.copyOf(in, vecSize);
+        for (int i = vecSize; i < in.length; ++i)
+            rslt[i % vecSize] = Math.max(rslt[i % vecSize], in[i]);
+        return rslt;
+    }
+
+    private static int firstZero(long[] in, int vecSize) {
+        for (int cell = 0; cell < INPUT_LEN; ++cell) {
+            boolean zero = true;
+            for (int c = 0; c < vecSize; ++c)
+                zero &= (in[cell * vecSize + c] == 0);
+            if (zero) return cell;
+        }
+        return -1;
+    }
+
+    private static int firstZero(double[] in, int vecSize) {
+        for (int cell = 0; cell < INPUT_LEN; ++cell) {
+            boolean zero = true;
+            for (int c = 0; c < vecSize; ++c)
+                zero &= (in[cell * vecSize + c] == 0);
+            if (zero) return cell;
+        }
+        return -1;
+    }
+
+    ///////////////////////////////////////////////////////////////////
+    // Reflected results as arrays of components
+    ///////////////////////////////////////////////////////////////////
+
+    private static long[] values(byte v) { return new long[] { v }; }
+    private static long[] values(short v) { return new long[] { v }; }
+    private static long[] values(int v) { return new long[] { v }; }
+    private static long[] values(long v) { return new long[] { v }; }
+    private static long[] values(Byte2 v) { return new long[] { v.x, v.y }; }
+    private static long[] values(Byte3 v) { return new long[] { v.x, v.y, v.z }; }
+    private static long[] values(Byte4 v) { return new long[] { v.x, v.y, v.z, v.w }; }
+    private static long[] values(Short2 v) { return new long[] { v.x, v.y }; }
+    private static long[] values(Short3 v) { return new long[] { v.x, v.y, v.z }; }
+    private static long[] values(Short4 v) { return new long[] { v.x, v.y, v.z, v.w }; }
+    private static long[] values(Int2 v) { return new long[] { v.x, v.y }; }
+    private static long[] values(Int3 v) { return new long[] { v.x, v.y, v.z }; }
+    private static long[] values(Int4 v) { return new long[] { v.x, v.y, v.z, v.w }; }
+    private static long[] values(Long2 v) { return new long[] { v.x, v.y }; }
+    private static long[] values(Long3 v) { return new long[] { v.x, v.y, v.z }; }
+    private static long[] values(Long4 v) { return new long[] { v.x, v.y, v.z, v.w }; }
+    private static double[] values(float v) { return new double[] { v }; }
+    private static double[] values(double v) { return new double[] { v }; }
+    private static double[] values(Float2 v) { return new double[] { v.x, v.y }; }
+    private static double[] values(Float3 v) { return new double[] { v.x, v.y, v.z }; }
+    private static double[] values(Float4 v) { return new double[] { v.x, v.y, v.z, v.w }; }
+    private static double[] values(Double2 v) { return new double[] { v.x, v.y }; }
+    private static double[] values(Double3 v) { return new double[] { v.x, v.y, v.z }; }
+    private static double[] values(Double4 v) { return new double[] { v.x, v.y, v.z, v.w }; }
+
+    private boolean result(String testName, String javaRslt, String rsRslt) {
+        final boolean success = javaRslt.equals(rsRslt);
+        Log.i(TAG,
+                testName + ": java " + javaRslt + ", rs " + rsRslt + ": " +
+                (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    private boolean result(String testName, long[] javaRslt, long[] rsRslt) {
+        return result(testName, Arrays.toString(javaRslt), Arrays.toString(rsRslt));
+    }
+
+    private boolean result(String testName, double[] javaRslt, double[] rsRslt) {
+        return result(testName, Arrays.toString(javaRslt), Arrays.toString(rsRslt));
+    }
+
+    private boolean result(String testName, int javaRslt, int rsRslt) {
+        return result(testName, Integer.toString(javaRslt), Integer.toString(rsRslt));
+    }
+'''
+
+JAVA_FOOTER = '''\
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_reduce_types s = new ScriptC_reduce_types(pRS);
+        Random rand = new Random(0x1e5a);
+
+        final boolean half = halfSupported(pRS);
+        if (!half)
+            Log.i(TAG, "half not supported here: skipping the half cases");
+
+        boolean pass = true;
+%(calls)s
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
+'''
+
+
+def vec_name(base, vec):
+    return base if vec == 1 else '%s%d' % (base, vec)
+
+
+def components(vec):
+    return [''] if vec == 1 else ['.' + c for c in 'xyzw'[:vec]]
+
+
+def gen_rs_type(rs_type, vec):
+    t = vec_name(rs_type, vec)
+    comps = components(vec)
+
+    def acc(c):
+        return '*accum' if c == '' else 'accum->' + c[1:]
+
+    lo, hi = LIMITS[rs_type]
+    out = []
+    out.append('/////////////////////////////////////////////////////////////////////////\n')
+    out.append('// %s\n\n' % t)
+
+    if rs_type != 'half':
+        out.append('#pragma rs reduce(sum_%s) accumulator(sum_%s_accum)\n\n' % (t, t))
+        out.append('static void sum_%s_accum(%s *accum, %s in) { *accum += in; }\n\n' % (t, t, t))
+
+    for op, cmp_op, init in (('min', '<', hi), ('max', '>', lo)):
+        out.append('#pragma rs reduce(%s_%s) \\\n' % (op, t))
+        out.append('  initializer(%s_%s_init) accumulator(%s_%s_accum)\n\n' % (op, t, op, t))
+        out.append('static void %s_%s_init(%s *accum) {\n' % (op, t, t))
+        for c in comps:
+            out.append('  %s = %s;\n' % (acc(c), init))
+        out.append('}\n\n')
+        out.append('static void %s_%s_accum(%s *accum, %s in) {\n' % (op, t, t, t))
+        for c in comps:
+            out.append('  if (in%s %s %s) %s = in%s;\n' % (c, cmp_op, acc(c), acc(c), c))
+        out.append('}\n\n')
+
+    out.append('#pragma rs reduce(ffz_%s) \\\n' % t)
+    out.append('  initializer(ffzInit) accumulator(ffz_%s_accum) combiner(ffzCombine)\n\n' % t)
+    out.append('static void ffz_%s_accum(int *accum, %s in, int x /* special arg */) {\n' % (t, t))
+    zero = ' && '.join('(in%s == 0)' % c for c in comps)
+    out.append('  if (%s && ((*accum < 0) || (x < *accum)))\n' % zero)
+    out.append('    *accum = x;\n')
+    out.append('}\n\n')
+    return ''.join(out)
+
+
+def gen_java_type(rs_type, elem, kind, bits, array_type, vec):
+    t = vec_name(rs_type, vec)
+    elem_name = elem if vec == 1 else '%s_%d' % (elem, vec)
+    to_array = 'to%sArray' % array_type.capitalize()
+    limit = dict((r[0], r[5]) for r in TYPES)[rs_type]
+
+    out = []
+    out.append('    private boolean test_%s(RenderScript RS, ScriptC_reduce_types s, Random rand) {\n' % t)
+    if kind in ('FLOAT', 'HALF'):
+        out.append('        final double[] input = randomFloats(rand, %d);\n' % vec)
+    else:
+        out.append('        final long[] input = randomIntegers(rand, %d, %d, Kind.%s, %d);\n'
+                   % (vec, bits, kind, limit or 0))
+    out.append('        plantZeros(rand, input, %d);\n' % vec)
+    out.append('        Allocation ain = createInput(RS, Element.%s(RS), %d);\n' % (elem_name, vec))
+    out.append('        ain.copyFrom(%s(input));\n\n' % to_array)
+    out.append('        boolean pass = true;\n')
+    if kind == 'HALF':
+        sum_ref = None
+        min_ref = 'halfBits(min(input, %d))' % vec
+        max_ref = 'halfBits(max(input, %d))' % vec
+    elif kind == 'FLOAT':
+        sum_ref = 'sum(input, %d)' % vec
+        min_ref = 'min(input, %d)' % vec
+        max_ref = 'max(input, %d)' % vec
+    else:
+        sum_ref = 'sum(input, %d, %d, Kind.%s)' % (vec, bits, kind)
+        min_ref = 'min(input, %d, Kind.%s)' % (vec, kind)
+        max_ref = 'max(input, %d, Kind.%s)' % (vec, kind)
+    for op, ref in (('sum', sum_ref), ('min', min_ref), ('max', max_ref)):
+        if ref is None:
+            continue
+        out.append('        pass &= result("%s_%s", %s,\n' % (op, t, ref))
+        out.append('                values(s.reduce_%s_%s(ain).get()));\n' % (op, t))
+    out.append('        pass &= result("ffz_%s", firstZero(input, %d),\n' % (t, vec))
+    out.append('                s.reduce_ffz_%s(ain).get());\n' % t)
+    out.append('        ain.destroy();\n')
+    out.append('        return pass;\n')
+    out.append('    }\n\n')
+    return ''.join(out)
+
+
+def generate():
+    rs = [RS_HEADER, '\n']
+    java = [JAVA_HEADER.replace('@INPUT_LEN@', str(INPUT_LEN)), '\n']
+    java.append('    ///////////////////////////////////////////////////////////////////\n')
+    java.append('    // Generated test cases\n')
+    java.append('    ///////////////////////////////////////////////////////////////////\n\n')
+    calls = []
+    for rs_type, elem, kind, bits, array_type, _ in TYPES:
+        if rs_type == 'half':
+            calls.append('        if (half) {')
+        indent = '            ' if rs_type == 'half' else '        '
+        for vec in VEC_SIZES:
+            rs.append(gen_rs_type(rs_type, vec))
+            java.append(gen_java_type(rs_type, elem, kind, bits, array_type, vec))
+            calls.append(indent + 'pass &= test_%s(pRS, s, rand);' % vec_name(rs_type, vec))
+        if rs_type == 'half':
+            calls.append('        }')
+    java.append(JAVA_FOOTER % {'calls': '\n'.join(calls)})
+    return {
+        'reduce_types.rs': ''.join(rs).rstrip('\n') + '\n',
+        'UT_reduce_types.java': ''.join(java),
+    }
+
+
+def main(argv):
+    check = False
+    for arg in argv[1:]:
+        if arg == '--check':
+            check = True
+        else:
+            sys.stderr.write(__doc__)
+            return 2
+
+    stale = False
+    for name, text in sorted(generate().items()):
+        path = os.path.join(SRC_DIR, name)
+        if check:
+            if not os.path.exists(path) or open(path).read() != text:
+                sys.stderr.write('%s is out of date\n' % name)
+                stale = True
+        else:
+            with open(path, 'w') as f:
+                f.write(text)
+    return 1 if stale else 0
+
+
+if __name__ == '__main__':
+    sys.exit(main(sys.argv))
@@ -0,0 +1,161 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.android.rs.test_compat;
+
+import android.util.Log;
+
+import java.io.BufferedReader;
+import java.io.File;
+import java.io.FileReader;
+import java.io.FileWriter;
+import java.io.IOException;
+import java.util.HashSet;
+import java.util.Map;
+import java.util.Set;
+import java.util.TreeMap;
+
+/* Records the outputs of one unit test into a golden file, or compares
+ * them against a previously recorded golden file, so that driver changes
+ * can be checked bit-for-bit wherever results are expected to be
+ * deterministic.
+ *
+ * A golden file holds one "key=value" line per checked result.  Values
+ * are compared as strings, so only record results that are exact and
+ * independent of how the driver splits the work (integers, indices that
+ * the kernel defines uniquely, and so on).
+ *
+ * A golden file is only written when the test passed, and a replay fails
+ * if the golden file holds any result that the test never checked.
+ */
+public class GoldenResults {
+    private static final String TAG = "GoldenResults";
+
+    public enum Mode {
+        OFF,     // don't record or compare anything
+        RECORD,  // write every checked result to the golden file
+        REPLAY   // compare every checked result against the golden file
+    }
+
+    private final Mode mMode;
+    private final File mFile;
+    private final Map<String, String> mValues = new TreeMap<String, String>();
+    private final Set<String> mChecked = new HashSet<String>();
+    private boolean mLoadFailed;
+    private boolean mFinished;
+
+    public GoldenResults(Mode mode, File dir, String testName) {
+        mMode = (dir == null) ? Mode.OFF : mode;
+        mFile = (dir == null) ? null : new File(dir, testName + ".golden");
+        if (mMode == Mode.REPLAY)
+            load();
+    }
+
+    public Mode getMode() {
+        return mMode;
+    }
+
+    /* Returns false if this result disagrees with the golden file (REPLAY
+     * only); recording or OFF always succeeds.
+     */
+    public synchronized boolean check(String key, String value) {
+        switch (mMode) {
+            case RECORD:
+                mValues.put(key, value);
+                return true;
+            case REPLAY:
+                mChecked.add(key);
+                if (mLoadFailed)
+                    return false;
+                String golden = mValues.get(key);
+                if (golden == null) {
+                    Log.e(TAG, mFile + ": no golden value for " + key);
+                    return false;
+                }
+                if (!golden.equals(value)) {
+                    Log.e(TAG, mFile + ": " + key + ": golden " + golden + ", got " + value);
+                    return false;
+                }
+                return true;
+            default:
+                return true;
+        }
+    }
+
+    /* Called once the test result is known.  When recording, writes out
+     * the golden file, but only if the test passed.  When replaying,
+     * returns false if any golden value was never checked.
+     */
+    public synchronized boolean finish(boolean testPassed) {
+        if (mFinished)
+            return true;
+        mFinished = true;
+        switch (mMode) {
+            case RECORD:
+                if (testPassed)
+                    write();
+                else
+                    Log.e(TAG, "test failed; not recording " + mFile);
+                return true;
+            case REPLAY:
+                boolean allChecked = !mLoadFailed;
+                for (String key : mValues.keySet()) {
+                    if (!mChecked.contains(key)) {
+                        Log.e(TAG, mFile + ": golden value for " + key + " was never checked");
+                        allChecked = false;
+                    }
+                }
+                return allChecked;
+            default:
+                return true;
+        }
+    }
+
+    private void write() {
+        mFile.getParentFile().mkdirs();
+        try {
+            FileWriter writer = new FileWriter(mFile);
+            try {
+                for (Map.Entry<String, String> e : mValues.entrySet())
+                    writer.write(e.getKey() + "=" + e.getValue() + "\n");
+            } finally {
+                writer.close();
+            }
+            Log.i(TAG, "recorded " + mValues.size() + " results to " + mFile);
+        } catch (IOException e) {
+            Log.e(TAG, "unable to write " + mFile, e);
+        }
+    }
+
+    private void load() {
+        try {
+            BufferedReader reader = new BufferedReader(new FileReader(mFile));
+            try {
+                String line;
+                while ((line = reader.readLine()) != null) {
+                    int eq = line.indexOf('=');
+                    if (eq > 0)
+                        mValues.put(line.substring(0, eq), line.substring(eq + 1));
+                }
+            } finally {
+                reader.close();
+            }
+        } catch (IOException e) {
+            Log.e(TAG, "unable to read " + mFile, e);
+            mLoadFailed = true;
+        }
+    }
+}
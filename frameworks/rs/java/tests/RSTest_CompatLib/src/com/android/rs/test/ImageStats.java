@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.android.rs.test_compat;
+
+import android.support.v8.renderscript.*;
+
+/* Pixel count and per-channel mean, variance, minimum, maximum and
+ * 256-bucket histogram of a U8, U8_4 or F32 allocation, computed by a
+ * single reduction of image_stats.rs.
+ *
+ * Variance is the population variance.  F32 histograms bucket [0, 1),
+ * counting values outside that range in the first or last bucket.
+ *
+ * A result with count 0 has mean and variance 0 and an empty histogram.
+ * Its min and max keep the reduction's initial values, so min > max:
+ * min 255 and max 0 for U8 and U8_4, min +inf and max -inf for F32.
+ */
+public class ImageStats {
+    public static final int BUCKETS = ScriptC_image_stats.const_bucketCount;
+
+    private static final int U8_CHANNEL_LEN = ScriptC_image_stats.const_u8ChannelLen;
+    private static final int F32_LEN = ScriptC_image_stats.const_f32Len;
+
+    public final long count;
+    public final int channels;
+    public final double[] mean;
+    public final double[] variance;
+    public final double[] min;
+    public final double[] max;
+    public final long[][] histogram;  // [channel][bucket]
+
+    private ImageStats(long count, int channels) {
+        this.count = count;
+        this.channels = channels;
+        mean = new double[channels];
+        variance = new double[channels];
+        min = new double[channels];
+        max = new double[channels];
+        histogram = new long[channels][BUCKETS];
+    }
+
+    public static ImageStats compute(ScriptC_image_stats s, Allocation input) {
+        final Element e = input.getElement();
+        final Element.DataType dataType = e.getDataType();
+        final int vecSize = e.getVectorSize();
+        if (dataType == Element.DataType.UNSIGNED_8 && vecSize == 1)
+            return fromU8(s.reduce_imageStatsU8(input).get(), 1);
+        if (dataType == Element.DataType.UNSIGNED_8 && vecSize == 4)
+            return fromU8(s.reduce_imageStatsU8_4(input).get(), 4);
+        if (dataType == Element.DataType.FLOAT_32 && vecSize == 1)
+            return fromF32(s.reduce_imageStatsF32(input).get());
+        throw new RSIllegalArgumentException(
+                "ImageStats: unsupported element " + dataType + "_" + vecSize);
+    }
+
+    private static ImageStats fromU8(long[] rslt, int channels) {
+        ImageStats stats = new ImageStats(rslt[0], channels);
+        for (int ch = 0; ch < channels; ++ch) {
+            final int base = 1 + ch * U8_CHANNEL_LEN;
+            if (stats.count > 0) {
+                stats.mean[ch] = (double) rslt[base] / stats.count;
+                stats.variance[ch] =
+                        (double) rslt[base + 1] / stats.count - stats.mean[ch] * stats.mean[ch];
+            }
+            stats.min[ch] = rslt[base + 2];
+            stats.max[ch] = rslt[base + 3];
+            System.arraycopy(rslt, base + 4, stats.histogram[ch], 0, BUCKETS);
+        }
+        return stats;
+    }
+
+    private static ImageStats fromF32(double[] rslt) {
+        ImageStats stats = new ImageStats((long) rslt[0], 1);
+        stats.mean[0] = rslt[1];
+        stats.variance[0] = (stats.count > 0) ? rslt[2] / stats.count : 0;
+        stats.min[0] = rslt[3];
+        stats.max[0] = rslt[4];
+        for (int i = 0; i < BUCKETS; ++i)
+            stats.histogram[0][i] = (long) rslt[5 + i];
+        return stats;
+    }
+}
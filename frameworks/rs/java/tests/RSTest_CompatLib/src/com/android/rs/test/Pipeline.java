@@ -0,0 +1,129 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.android.rs.test_compat;
+
+import android.support.v8.renderscript.*;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/* Runs an ordered list of foreach steps, each reading the previous step's
+ * output, and manages the intermediate allocations: they are created
+ * lazily with the Type each step needs, handed back to a pool as soon as
+ * the next step has consumed them (so a chain of same-typed steps
+ * ping-pongs between two buffers), reused across run() calls, and
+ * destroyed by release().
+ *
+ * A reduce consumes an allocation but produces a Java result rather than
+ * an allocation, so it can only end a pipeline: apply it to the
+ * allocation run() returns.
+ */
+public class Pipeline {
+    /* One foreach step, e.g. "s.forEach_kernel(in, out)". */
+    public interface Kernel {
+        void forEach(Allocation in, Allocation out);
+    }
+
+    /* The Type of a step's output, given the Type of its input. */
+    public interface OutputType {
+        Type of(RenderScript rs, Type in);
+    }
+
+    private static class Step {
+        final Kernel kernel;
+        final OutputType outputType;
+
+        Step(Kernel kernel, OutputType outputType) {
+            this.kernel = kernel;
+            this.outputType = outputType;
+        }
+    }
+
+    private final RenderScript mRS;
+    private final List<Step> mSteps = new ArrayList<Step>();
+    private final List<Allocation> mFree = new ArrayList<Allocation>();
+    private final List<Allocation> mAll = new ArrayList<Allocation>();
+    private Allocation mOutput;
+
+    public Pipeline(RenderScript rs) {
+        mRS = rs;
+    }
+
+    /* Adds a step whose output has the same Type as its input. */
+    public Pipeline add(Kernel kernel) {
+        return add(kernel, null);
+    }
+
+    public Pipeline add(Kernel kernel, OutputType outputType) {
+        mSteps.add(new Step(kernel, outputType));
+        return this;
+    }
+
+    /* Runs every step and returns the last step's output, or input itself
+     * if there are no steps.  The returned allocation belongs to the
+     * pipeline and is only valid until the next run() or release().
+     */
+    public Allocation run(Allocation input) {
+        if (mOutput != null) {
+            mFree.add(mOutput);
+            mOutput = null;
+        }
+        Allocation in = input;
+        for (Step step : mSteps) {
+            final Type outType = (step.outputType == null)
+                    ? in.getType() : step.outputType.of(mRS, in.getType());
+            Allocation out = acquire(outType);
+            step.kernel.forEach(in, out);
+            if (in != input)
+                mFree.add(in);
+            in = out;
+        }
+        if (in != input)
+            mOutput = in;
+        return in;
+    }
+
+    /* Number of intermediate allocations created so far. */
+    public int getAllocationCount() {
+        return mAll.size();
+    }
+
+    /* Destroys every intermediate allocation. */
+    public void release() {
+        for (Allocation a : mAll)
+            a.destroy();
+        mAll.clear();
+        mFree.clear();
+        mOutput = null;
+    }
+
+    private Allocation acquire(Type type) {
+        for (int i = 0; i < mFree.size(); ++i) {
+            if (sameType(mFree.get(i).getType(), type))
+                return mFree.remove(i);
+        }
+        Allocation a = Allocation.createTyped(mRS, type);
+        mAll.add(a);
+        return a;
+    }
+
+    private static boolean sameType(Type a, Type b) {
+        return (a.getX() == b.getX()) && (a.getY() == b.getY()) && (a.getZ() == b.getZ()) &&
+                (a.hasFaces() == b.hasFaces()) && (a.hasMipmaps() == b.hasMipmaps()) &&
+                a.getElement().isCompatible(b.getElement());
+    }
+}
@@ -53,9 +53,28 @@ public class RSTest extends ListActivity {
         mRS = RenderScript.create(this);
 
         RSTC = new RSTestCore(this);
+        RSTC.setGoldenMode(getGoldenMode());
         RSTC.init(mRS, getResources());
     }
 
+    /* Golden results are recorded or replayed when the activity is started
+     * with a "golden" extra, e.g.
+     *   adb shell am start -n com.android.rs.test_compat/.RSTest --es golden record
+     */
+    private GoldenResults.Mode getGoldenMode() {
+        String mode = getIntent().getStringExtra("golden");
+        if ("record".equals(mode)) {
+            return GoldenResults.Mode.RECORD;
+        }
+        if ("replay".equals(mode)) {
+            return GoldenResults.Mode.REPLAY;
+        }
+        if (mode != null) {
+            log("Unknown golden mode: " + mode);
+        }
+        return GoldenResults.Mode.OFF;
+    }
+
     static void log(String message) {
         if (LOG_ENABLED) {
             Log.v(LOG_TAG, message);
@@ -20,6 +20,7 @@ import android.content.Context;
 import android.content.res.Resources;
 import android.support.v8.renderscript.*;
 import android.util.Log;
+import java.io.File;
 import java.util.ArrayList;
 import java.util.ListIterator;
 import java.util.Timer;
@@ -44,12 +45,31 @@ public class RSTestCore {
 
     private ScriptField_ListAllocs_s mListAllocs;
 
+    private GoldenResults.Mode mGoldenMode = GoldenResults.Mode.OFF;
+
     private ArrayAdapter<UnitTest> testAdapter;
 
     /* Periodic timer for ensuring future tests get scheduled */
     private Timer mTimer;
     public static final int RS_TIMER_PERIOD = 100;
 
+    public void setGoldenMode(GoldenResults.Mode mode) {
+        mGoldenMode = mode;
+    }
+
+    /* Golden files live in the app's external files directory, so that they
+     * can be pulled after a RECORD run and pushed back before a REPLAY run.
+     */
+    public GoldenResults openGoldenResults(String testName) {
+        File dir = null;
+        if (mGoldenMode != GoldenResults.Mode.OFF) {
+            dir = mCtx.getExternalFilesDir("golden");
+            if (dir == null)
+                RSTest.log("External storage unavailable, golden results disabled");
+        }
+        return new GoldenResults(mGoldenMode, dir, testName);
+    }
+
     public void init(RenderScript rs, Resources res) {
         mRS = rs;
         mRes = res;
@@ -86,15 +106,21 @@ public class RSTestCore {
         unitTests.add(new UT_script_group2_gatherscatter(this, mRes, mCtx));
         unitTests.add(new UT_script_group2_nochain(this, mRes, mCtx));
         unitTests.add(new UT_script_group2_float(this, mRes, mCtx));
+        unitTests.add(new UT_pipeline(this, mRes, mCtx));
         unitTests.add(new UT_single_source_script(this, mRes, mCtx));
         unitTests.add(new UT_single_source_alloc(this, mRes, mCtx));
         unitTests.add(new UT_single_source_ref_count(this, mRes, mCtx));
+        unitTests.add(new UT_introspection(this, mRes, mCtx));
         unitTests.add(new UT_noroot(this, mRes, mCtx));
         unitTests.add(new UT_atomic(this, mRes, mCtx));
+        unitTests.add(new UT_atomic_float(this, mRes, mCtx));
         unitTests.add(new UT_struct(this, mRes, mCtx));
         unitTests.add(new UT_math(this, mRes, mCtx));
         unitTests.add(new UT_math_conformance(this, mRes, mCtx));
         unitTests.add(new UT_math_agree(this, mRes, mCtx));
+        unitTests.add(new UT_saturating(this, mRes, mCtx));
+        unitTests.add(new UT_rng(this, mRes, mCtx));
+        unitTests.add(new UT_complex(this, mRes, mCtx));
         unitTests.add(new UT_min(this, mRes, mCtx));
         unitTests.add(new UT_int4(this, mRes, mCtx));
         unitTests.add(new UT_element(this, mRes, mCtx));
@@ -102,6 +128,12 @@ public class RSTestCore {
         unitTests.add(new UT_fp_mad(this, mRes, mCtx));
         unitTests.add(new UT_reduce(this, mRes, mCtx));
         unitTests.add(new UT_reduce_backward(this, mRes, mCtx));
+        unitTests.add(new UT_reduce_types(this, mRes, mCtx));
+        unitTests.add(new UT_fixed_point(this, mRes, mCtx));
+        unitTests.add(new UT_reduce_properties(this, mRes, mCtx));
+        unitTests.add(new UT_reduce_vec3(this, mRes, mCtx));
+        unitTests.add(new UT_histeq(this, mRes, mCtx));
+        unitTests.add(new UT_image_stats(this, mRes, mCtx));
 
         /*
         unitTests.add(new UnitTest(null, "<Pass>", 1));
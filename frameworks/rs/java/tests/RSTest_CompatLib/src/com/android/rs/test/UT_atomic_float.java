@@ -0,0 +1,105 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Checks atomic_float.rsh's atomicAdd, directly and under contention
+ * from a scatter kernel.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Random;
+
+public class UT_atomic_float extends UnitTest {
+    private static final String TAG = "atomic_float";
+    private static final int BINS = 7;  // as in atomic_float.rs
+    private static final int INPUT_LEN = 100003;
+
+    protected UT_atomic_float(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "atomic_float", ctx);
+    }
+
+    private boolean helpers(RenderScript RS, ScriptC_atomic_float s) {
+        Allocation helpersFailed = Allocation.createSized(RS, Element.I32(RS), 1);
+        s.set_gHelpersFailed(helpersFailed);
+        s.invoke_helpers_test_run();
+        int[] failed = new int[1];
+        helpersFailed.copyTo(failed);
+        helpersFailed.destroy();
+        final boolean success = (failed[0] == 0);
+        Log.i(TAG, "helpers: " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    // Inputs are small integers and every partial sum stays below 2^24,
+    // so the result is exact whatever order the adds happen in.
+    private boolean scatter(RenderScript RS, ScriptC_atomic_float s, int seed) {
+        Random rand = new Random(seed);
+        float[] input = new float[INPUT_LEN];
+        float[] expected = new float[BINS + 1];
+        for (int x = 0; x < INPUT_LEN; ++x) {
+            input[x] = rand.nextInt(16);
+            expected[x % BINS] += input[x];
+            expected[BINS] += input[x];
+        }
+
+        Allocation in = Allocation.createSized(RS, Element.F32(RS), INPUT_LEN);
+        in.copyFrom(input);
+        Allocation result = Allocation.createSized(RS, Element.F32(RS), BINS + 1);
+        s.set_gResult(result);
+        s.invoke_clear();
+        s.forEach_scatter(in);
+        s.invoke_getResult();
+        float[] rsResult = new float[BINS + 1];
+        result.copyTo(rsResult);
+        in.destroy();
+        result.destroy();
+
+        boolean success = true;
+        for (int i = 0; i <= BINS; ++i) {
+            if (rsResult[i] != expected[i]) {
+                Log.e(TAG, "scatter seed " + seed + " cell " + i +
+                        ": rs " + rsResult[i] + ", java " + expected[i]);
+                success = false;
+            }
+        }
+        Log.i(TAG, "scatter seed " + seed + ": " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_atomic_float s = new ScriptC_atomic_float(pRS);
+
+        boolean pass = true;
+        pass &= helpers(pRS, s);
+        // Run twice, so that clear() is exercised on dirty bins.
+        pass &= scatter(pRS, s, 0);
+        pass &= scatter(pRS, s, 1);
+
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
@@ -0,0 +1,122 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Checks complex.rsh's helpers and the float2 reductions built on them. */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Random;
+
+public class UT_complex extends UnitTest {
+    private static final String TAG = "complex";
+    private static final int INPUT_LEN = 1000;
+
+    protected UT_complex(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "complex", ctx);
+    }
+
+    private boolean helpers(RenderScript RS, ScriptC_complex s) {
+        Allocation helpersFailed = Allocation.createSized(RS, Element.I32(RS), 1);
+        s.set_gHelpersFailed(helpersFailed);
+        s.invoke_helpers_test_run();
+        int[] failed = new int[1];
+        helpersFailed.copyTo(failed);
+        helpersFailed.destroy();
+        final boolean success = (failed[0] == 0);
+        Log.i(TAG, "helpers: " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    // Interleaved (re, im) pairs of small integers, so that every product
+    // and partial sum below is exact and the results do not depend on
+    // how the reduction is split.
+    private static float[] createInput(Random rand) {
+        float[] input = new float[2 * INPUT_LEN];
+        for (int i = 0; i < input.length; ++i)
+            input[i] = rand.nextInt(17) - 8;
+        return input;
+    }
+
+    private static Allocation createAllocation(RenderScript RS, float[] input) {
+        Allocation a = Allocation.createSized(RS, Element.F32_2(RS), INPUT_LEN);
+        a.copyFrom(input);
+        return a;
+    }
+
+    private boolean powerSum(RenderScript RS, ScriptC_complex s, float[] z) {
+        float javaRslt = 0;
+        for (int i = 0; i < INPUT_LEN; ++i)
+            javaRslt += z[2 * i] * z[2 * i] + z[2 * i + 1] * z[2 * i + 1];
+
+        Allocation in = createAllocation(RS, z);
+        final float rsRslt = s.reduce_powerSum(in).get();
+        in.destroy();
+
+        final boolean success = (javaRslt == rsRslt);
+        Log.i(TAG, "powerSum: java " + javaRslt + ", rs " + rsRslt +
+                ": " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    private boolean correlate(RenderScript RS, ScriptC_complex s, float[] a, float[] b) {
+        // sum of a * conj(b)
+        float javaRe = 0, javaIm = 0;
+        for (int i = 0; i < INPUT_LEN; ++i) {
+            final float ar = a[2 * i], ai = a[2 * i + 1];
+            final float br = b[2 * i], bi = b[2 * i + 1];
+            javaRe += ar * br + ai * bi;
+            javaIm += ai * br - ar * bi;
+        }
+
+        Allocation inA = createAllocation(RS, a);
+        Allocation inB = createAllocation(RS, b);
+        final Float2 rsRslt = s.reduce_correlate(inA, inB).get();
+        inA.destroy();
+        inB.destroy();
+
+        final boolean success = (javaRe == rsRslt.x) && (javaIm == rsRslt.y);
+        Log.i(TAG, "correlate: java (" + javaRe + ", " + javaIm + "), rs (" +
+                rsRslt.x + ", " + rsRslt.y + "): " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_complex s = new ScriptC_complex(pRS);
+        Random rand = new Random(0);
+
+        final float[] a = createInput(rand);
+        final float[] b = createInput(rand);
+
+        boolean pass = true;
+        pass &= helpers(pRS, s);
+        pass &= powerSum(pRS, s, a);
+        pass &= correlate(pRS, s, a, b);
+
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Random;
+
+public class UT_fixed_point extends UnitTest {
+    private static final String TAG = "fixed_point";
+
+    protected UT_fixed_point(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "fixed_point", ctx);
+    }
+
+    private float[] createInputArrayFloat(int len, int seed, float lo, float hi) {
+        Random rand = new Random(seed);
+        float[] array = new float[len];
+        for (int i = 0; i < len; ++i)
+            array[i] = lo + (hi - lo) * rand.nextFloat();
+        return array;
+    }
+
+    private static int saturate(long wide) {
+        return (int)Math.max(Integer.MIN_VALUE, Math.min(Integer.MAX_VALUE, wide));
+    }
+
+    // Must match <q>_fromFloat() in fixed_point.rsh.
+    private static int fromFloat(float f, int fracBits) {
+        final double scaled = Math.rint(f * (float)(1 << fracBits));
+        if (Double.isNaN(scaled))
+            return 0;
+        if (scaled >= 2147483648.0)
+            return Integer.MAX_VALUE;
+        if (scaled < -2147483648.0)
+            return Integer.MIN_VALUE;
+        return (int)scaled;
+    }
+
+    private static int sum(float[] input, int fracBits) {
+        long wide = 0;
+        for (float f : input)
+            wide += fromFloat(f, fracBits);
+        return saturate(wide);
+    }
+
+    private boolean result(String testName, int javaRslt, int rsRslt) {
+        final boolean success =
+                (javaRslt == rsRslt) & checkGolden(testName, Integer.toHexString(rsRslt));
+        Log.i(TAG,
+                testName + ": java " + Integer.toHexString(javaRslt) +
+                ", rs " + Integer.toHexString(rsRslt) + ": " +
+                (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    private boolean sumQ16_16(ScriptC_fixed_point s) {
+        final float[] input = createInputArrayFloat(100000, 0, -100.f, 100.f);
+        return result("sumQ16_16", sum(input, 16), s.reduce_sumQ16_16(input).get());
+    }
+
+    private boolean sumQ8_24(ScriptC_fixed_point s) {
+        final float[] input = createInputArrayFloat(1000, 1, -1.f, 1.f);
+        return result("sumQ8_24", sum(input, 24), s.reduce_sumQ8_24(input).get());
+    }
+
+    // The total exceeds the Q8.24 range, so the result saturates.
+    private boolean sumQ8_24Saturated(ScriptC_fixed_point s) {
+        final float[] input = createInputArrayFloat(1000, 2, 0.5f, 1.f);
+        final int javaRslt = sum(input, 24);
+        if (javaRslt != Integer.MAX_VALUE) {
+            Log.e(TAG, "sumQ8_24Saturated: java sum did not saturate: FAILED");
+            return false;
+        }
+        return result("sumQ8_24Saturated", javaRslt, s.reduce_sumQ8_24(input).get());
+    }
+
+    // Sums every value with a saturating add, one at a time in order.
+    private static int sequentialSaturatingSum(float[] input, int fracBits) {
+        int sum = 0;
+        for (float f : input)
+            sum = saturate((long) sum + fromFloat(f, fracBits));
+        return sum;
+    }
+
+    // A long run of large positive values followed by a matching run of
+    // negative ones: the total is well inside the Q8.24 range, but partial
+    // sums leave it and come back.  Saturating at each step would give a
+    // result that depends on the order the values are combined in (and
+    // sequentially, a wrong one); saturating only the exact total does not.
+    private boolean sumQ8_24Excursion(ScriptC_fixed_point s) {
+        final int half = 500;
+        final float[] random = createInputArrayFloat(2 * half, 3, -0.1f, 0.1f);
+        float[] input = new float[2 * half];
+        for (int i = 0; i < half; ++i) {
+            input[i] = 0.75f + random[i];
+            input[half + i] = -0.75f + random[half + i];
+        }
+        final int javaRslt = sum(input, 24);
+        if (sequentialSaturatingSum(input, 24) == javaRslt) {
+            Log.e(TAG, "sumQ8_24Excursion: partial sums never left the range: FAILED");
+            return false;
+        }
+        return result("sumQ8_24Excursion", javaRslt, s.reduce_sumQ8_24(input).get());
+    }
+
+    private boolean helpers(RenderScript RS, ScriptC_fixed_point s) {
+        Allocation helpersFailed = Allocation.createSized(RS, Element.I32(RS), 1);
+        s.set_gHelpersFailed(helpersFailed);
+        s.invoke_helpers_test_run();
+        int[] failed = new int[1];
+        helpersFailed.copyTo(failed);
+        final boolean success = (failed[0] == 0);
+        Log.i(TAG, "helpers: " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_fixed_point s = new ScriptC_fixed_point(pRS);
+
+        boolean pass = true;
+        pass &= helpers(pRS, s);
+        pass &= sumQ16_16(s);
+        pass &= sumQ8_24(s);
+        pass &= sumQ8_24Saturated(s);
+        pass &= sumQ8_24Excursion(s);
+
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
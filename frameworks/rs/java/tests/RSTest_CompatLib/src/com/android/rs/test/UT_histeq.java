@@ -0,0 +1,121 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Runs the histogram -> CDF/LUT -> foreach pipeline of histeq.rs over a
+ * low-contrast image and checks the equalized image against a Java
+ * reference.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Arrays;
+import java.util.Random;
+
+public class UT_histeq extends UnitTest {
+    private static final String TAG = "histeq";
+
+    private static final int dimX = 320, dimY = 240;
+    private static final int bucketCount = 256;
+
+    protected UT_histeq(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "histeq", ctx);
+    }
+
+    // Values clustered around 100, as in an under-exposed photo.
+    private byte[] createImage(int seed) {
+        Random rand = new Random(seed);
+        byte[] image = new byte[dimX * dimY];
+        for (int i = 0; i < image.length; ++i) {
+            int v = (int) Math.round(100 + 12 * rand.nextGaussian());
+            image[i] = (byte) Math.max(0, Math.min(255, v));
+        }
+        return image;
+    }
+
+    // Must match computeLut() and equalize() in histeq.rs.
+    private byte[] equalize(byte[] image) {
+        long[] cdf = new long[bucketCount];
+        for (byte b : image)
+            ++cdf[b & 0xff];
+        for (int i = 1; i < bucketCount; ++i)
+            cdf[i] += cdf[i - 1];
+
+        long cdfMin = 0;
+        for (int i = 0; i < bucketCount && cdfMin == 0; ++i)
+            cdfMin = cdf[i];
+
+        final long denom = cdf[bucketCount - 1] - cdfMin;
+        byte[] lut = new byte[bucketCount];
+        for (int i = 0; i < bucketCount; ++i) {
+            if (denom == 0)
+                lut[i] = (byte) i;
+            else if (cdf[i] < cdfMin)
+                lut[i] = 0;
+            else
+                lut[i] = (byte) (((cdf[i] - cdfMin) * 255 + denom / 2) / denom);
+        }
+
+        byte[] out = new byte[image.length];
+        for (int i = 0; i < image.length; ++i)
+            out[i] = lut[image[i] & 0xff];
+        return out;
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_histeq s = new ScriptC_histeq(pRS);
+
+        final byte[] image = createImage(0);
+
+        Type.Builder typeBuilder = new Type.Builder(pRS, Element.U8(pRS));
+        typeBuilder.setX(dimX).setY(dimY);
+        Allocation in = Allocation.createTyped(pRS, typeBuilder.create());
+        Allocation out = Allocation.createTyped(pRS, in.getType());
+        in.copy2DRangeFrom(0, 0, dimX, dimY, image);
+
+        s.set_gHistogram(s.reduce_histogram(in).get());
+        s.invoke_computeLut();
+        s.forEach_equalize(in, out);
+
+        byte[] rsRslt = new byte[dimX * dimY];
+        out.copy2DRangeTo(0, 0, dimX, dimY, rsRslt);
+        pRS.finish();
+        pRS.destroy();
+
+        final byte[] javaRslt = equalize(image);
+        boolean pass = true;
+        for (int i = 0; i < javaRslt.length; ++i) {
+            if (javaRslt[i] != rsRslt[i]) {
+                Log.i(TAG,
+                        "histeq[" + (i % dimX) + ", " + (i / dimX) + "]: java " + (javaRslt[i] & 0xff) +
+                        ", rs " + (rsRslt[i] & 0xff) + ": FAILED");
+                pass = false;
+                break;
+            }
+        }
+        pass &= checkGolden("histeq", Integer.toString(Arrays.hashCode(rsRslt)));
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Checks ImageStats for U8, U8_4 and F32 images against Java references. */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Arrays;
+import java.util.Random;
+
+public class UT_image_stats extends UnitTest {
+    private static final String TAG = "image_stats";
+
+    private static final int dimX = 160, dimY = 120;
+    private static final int pixelCount = dimX * dimY;
+
+    protected UT_image_stats(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "image_stats", ctx);
+    }
+
+    // Computes the reference statistics of interleaved channels directly.
+    private static ImageStatsRef reference(double[] values, int channels, boolean unitRange) {
+        ImageStatsRef ref = new ImageStatsRef(channels);
+        for (int ch = 0; ch < channels; ++ch) {
+            double sum = 0, min = Double.POSITIVE_INFINITY, max = Double.NEGATIVE_INFINITY;
+            for (int i = ch; i < values.length; i += channels) {
+                final double v = values[i];
+                sum += v;
+                min = Math.min(min, v);
+                max = Math.max(max, v);
+                final int bucket = unitRange
+                        ? (int) Math.max(0.f, Math.min(ImageStats.BUCKETS - 1.f,
+                                                       (float) v * ImageStats.BUCKETS))
+                        : (int) v;
+                ++ref.histogram[ch][bucket];
+            }
+            final double mean = sum / pixelCount;
+            double m2 = 0;
+            for (int i = ch; i < values.length; i += channels)
+                m2 += (values[i] - mean) * (values[i] - mean);
+            ref.mean[ch] = mean;
+            ref.variance[ch] = m2 / pixelCount;
+            ref.min[ch] = min;
+            ref.max[ch] = max;
+        }
+        return ref;
+    }
+
+    private static class ImageStatsRef {
+        final double[] mean, variance, min, max;
+        final long[][] histogram;
+
+        ImageStatsRef(int channels) {
+            mean = new double[channels];
+            variance = new double[channels];
+            min = new double[channels];
+            max = new double[channels];
+            histogram = new long[channels][ImageStats.BUCKETS];
+        }
+    }
+
+    private static boolean close(double a, double b) {
+        return Math.abs(a - b) <= 1e-6 * Math.max(1, Math.max(Math.abs(a), Math.abs(b)));
+    }
+
+    private boolean result(String testName, ImageStatsRef ref, ImageStats stats) {
+        boolean success = (stats.count == pixelCount) && (stats.channels == ref.mean.length);
+        for (int ch = 0; success && ch < stats.channels; ++ch) {
+            success &= close(ref.mean[ch], stats.mean[ch]);
+            success &= close(ref.variance[ch], stats.variance[ch]);
+            success &= (ref.min[ch] == stats.min[ch]) && (ref.max[ch] == stats.max[ch]);
+            success &= Arrays.equals(ref.histogram[ch], stats.histogram[ch]);
+            if (!success) {
+                Log.i(TAG,
+                        testName + "[" + ch + "]: java mean " + ref.mean[ch] +
+                        " variance " + ref.variance[ch] + " min " + ref.min[ch] + " max " + ref.max[ch] +
+                        ", rs mean " + stats.mean[ch] + " variance " + stats.variance[ch] +
+                        " min " + stats.min[ch] + " max " + stats.max[ch]);
+            }
+        }
+        Log.i(TAG, testName + ": " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    private Allocation create2D(RenderScript RS, Element e) {
+        Type.Builder typeBuilder = new Type.Builder(RS, e);
+        typeBuilder.setX(dimX).setY(dimY);
+        return Allocation.createTyped(RS, typeBuilder.create());
+    }
+
+    private boolean statsU8(RenderScript RS, ScriptC_image_stats s, int channels, int seed) {
+        byte[] pixels = new byte[pixelCount * channels];
+        (new Random(seed)).nextBytes(pixels);
+        double[] values = new double[pixels.length];
+        for (int i = 0; i < pixels.length; ++i)
+            values[i] = pixels[i] & 0xff;
+
+        Allocation input = create2D(RS, channels == 1 ? Element.U8(RS) : Element.U8_4(RS));
+        input.copy2DRangeFrom(0, 0, dimX, dimY, pixels);
+
+        return result(channels == 1 ? "U8" : "U8_4",
+                reference(values, channels, false), ImageStats.compute(s, input));
+    }
+
+    private boolean statsF32(RenderScript RS, ScriptC_image_stats s) {
+        Random rand = new Random(2);
+        float[] pixels = new float[pixelCount];
+        double[] values = new double[pixelCount];
+        for (int i = 0; i < pixelCount; ++i) {
+            // mostly within [0, 1), with some outliers for the end buckets
+            pixels[i] = (float) (0.5 + 0.3 * rand.nextGaussian());
+            values[i] = pixels[i];
+        }
+
+        Allocation input = create2D(RS, Element.F32(RS));
+        input.copy2DRangeFrom(0, 0, dimX, dimY, pixels);
+
+        return result("F32", reference(values, 1, true), ImageStats.compute(s, input));
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_image_stats s = new ScriptC_image_stats(pRS);
+
+        boolean pass = true;
+        pass &= statsU8(pRS, s, 1, 0);
+        pass &= statsU8(pRS, s, 4, 1);
+        pass &= statsF32(pRS, s);
+
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
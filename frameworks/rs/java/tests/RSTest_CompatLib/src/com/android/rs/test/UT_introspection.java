@@ -0,0 +1,133 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+
+import java.util.Random;
+
+public class UT_introspection extends UnitTest {
+    private Resources mRes;
+    private Random rand;
+
+    // Mimicking enum rs_data_type in frameworks/rs/scriptc/rs_object_types.rsh
+    private static final int RS_TYPE_FLOAT_32    = 2;
+    private static final int RS_TYPE_SIGNED_16   = 5;
+    private static final int RS_TYPE_SIGNED_32   = 6;
+    private static final int RS_TYPE_UNSIGNED_8  = 8;
+
+    protected UT_introspection(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "Introspection", ctx);
+        mRes = res;
+        rand = new Random(0);
+    }
+
+    private Allocation createTyped(RenderScript RS, Element e, int dimX, int dimY, int dimZ) {
+        Type.Builder typeBuilder = new Type.Builder(RS, e);
+        typeBuilder.setX(dimX);
+        if (dimY != 0)
+            typeBuilder.setY(dimY);
+        if (dimZ != 0)
+            typeBuilder.setZ(dimZ);
+        return Allocation.createTyped(RS, typeBuilder.create());
+    }
+
+    private void checkAlloc(ScriptC_introspection s, Allocation a, int dataType, int vecSize,
+                            int dimX, int dimY, int dimZ, double expectedSum) {
+        s.set_gAlloc(a);
+        s.set_gDataType(dataType);
+        s.set_gVecSize(vecSize);
+        s.set_gDimX(dimX);
+        s.set_gDimY(dimY);
+        s.set_gDimZ(dimZ);
+        s.set_gExpectedSum(expectedSum);
+        s.invoke_check_alloc();
+    }
+
+    private void testUChar(RenderScript RS, ScriptC_introspection s) {
+        final int dimX = 37;
+        byte[] data = new byte[dimX];
+        rand.nextBytes(data);
+        double sum = 0;
+        for (byte b : data)
+            sum += (b & 0xff);
+
+        Allocation a = createTyped(RS, Element.U8(RS), dimX, 0, 0);
+        a.copyFrom(data);
+        checkAlloc(s, a, RS_TYPE_UNSIGNED_8, 1, dimX, 0, 0, sum);
+    }
+
+    private void testShort2(RenderScript RS, ScriptC_introspection s) {
+        final int dimX = 7, dimY = 5;
+        short[] data = new short[dimX * dimY * 2];
+        double sum = 0;
+        for (int i = 0; i < data.length; ++i) {
+            data[i] = (short) (rand.nextInt(1 << 16) - (1 << 15));
+            sum += data[i];
+        }
+
+        Allocation a = createTyped(RS, Element.I16_2(RS), dimX, dimY, 0);
+        a.copyFrom(data);
+        checkAlloc(s, a, RS_TYPE_SIGNED_16, 2, dimX, dimY, 0, sum);
+    }
+
+    private void testInt(RenderScript RS, ScriptC_introspection s) {
+        final int dimX = 11, dimY = 3;
+        int[] data = new int[dimX * dimY];
+        double sum = 0;
+        for (int i = 0; i < data.length; ++i) {
+            data[i] = rand.nextInt();
+            sum += data[i];
+        }
+
+        Allocation a = createTyped(RS, Element.I32(RS), dimX, dimY, 0);
+        a.copyFrom(data);
+        checkAlloc(s, a, RS_TYPE_SIGNED_32, 1, dimX, dimY, 0, sum);
+    }
+
+    private void testFloat4(RenderScript RS, ScriptC_introspection s) {
+        final int dimX = 3, dimY = 4, dimZ = 5;
+        float[] data = new float[dimX * dimY * dimZ * 4];
+        double sum = 0;
+        for (int i = 0; i < data.length; ++i) {
+            // Small integral values keep the sum exact regardless of the
+            // order in which the cells are visited.
+            data[i] = rand.nextInt(1000) - 500;
+            sum += data[i];
+        }
+
+        Allocation a = createTyped(RS, Element.F32_4(RS), dimX, dimY, dimZ);
+        a.copyFrom(data);
+        checkAlloc(s, a, RS_TYPE_FLOAT_32, 4, dimX, dimY, dimZ, sum);
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_introspection s = new ScriptC_introspection(pRS);
+        pRS.setMessageHandler(mRsMessage);
+        testUChar(pRS, s);
+        testShort2(pRS, s);
+        testInt(pRS, s);
+        testFloat4(pRS, s);
+        s.invoke_introspection_test();
+        pRS.finish();
+        waitForMessage();
+        pRS.destroy();
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Checks Pipeline's results and its reuse of intermediate allocations. */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Random;
+
+public class UT_pipeline extends UnitTest {
+    private static final String TAG = "pipeline";
+
+    protected UT_pipeline(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "pipeline", ctx);
+    }
+
+    // ((v + 1) * 2 + 1) * 2 as float, then halved, summed over the input.
+    // Inputs are small, so every value and the sum are exact floats.
+    private static float reference(int[] input) {
+        float sum = 0;
+        for (int v : input)
+            sum += (float) (((v + 1) * 2 + 1) * 2) * 0.5f;
+        return sum;
+    }
+
+    private Pipeline createPipeline(RenderScript RS, final ScriptC_pipeline s) {
+        Pipeline.Kernel addOne = new Pipeline.Kernel() {
+            public void forEach(Allocation in, Allocation out) { s.forEach_addOne(in, out); }
+        };
+        Pipeline.Kernel twice = new Pipeline.Kernel() {
+            public void forEach(Allocation in, Allocation out) { s.forEach_twice(in, out); }
+        };
+        Pipeline.Kernel toFloat = new Pipeline.Kernel() {
+            public void forEach(Allocation in, Allocation out) { s.forEach_toFloat(in, out); }
+        };
+        Pipeline.Kernel halve = new Pipeline.Kernel() {
+            public void forEach(Allocation in, Allocation out) { s.forEach_halve(in, out); }
+        };
+        Pipeline.OutputType floatOfSameShape = new Pipeline.OutputType() {
+            public Type of(RenderScript rs, Type in) {
+                return Type.createXY(rs, Element.F32(rs), in.getX(), Math.max(1, in.getY()));
+            }
+        };
+
+        return new Pipeline(RS)
+                .add(addOne).add(twice).add(addOne).add(twice)
+                .add(toFloat, floatOfSameShape)
+                .add(halve);
+    }
+
+    private boolean runOnce(RenderScript RS, ScriptC_pipeline s, Pipeline p,
+                            int dimX, int dimY, int seed, int expectedAllocations) {
+        Random rand = new Random(seed);
+        int[] input = new int[dimX * dimY];
+        for (int i = 0; i < input.length; ++i)
+            input[i] = rand.nextInt(1000);
+
+        Allocation in = Allocation.createTyped(RS, Type.createXY(RS, Element.I32(RS), dimX, dimY));
+        in.copyFrom(input);
+        final float rsRslt = s.reduce_sum(p.run(in)).get();
+        in.destroy();
+
+        final float javaRslt = reference(input);
+        final String testName = "pipeline " + dimX + "x" + dimY;
+        final boolean success =
+                (javaRslt == rsRslt) && (p.getAllocationCount() == expectedAllocations);
+        Log.i(TAG,
+                testName + ": java " + javaRslt + ", rs " + rsRslt +
+                ", allocations " + p.getAllocationCount() + " (expected " + expectedAllocations + ")" +
+                ": " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_pipeline s = new ScriptC_pipeline(pRS);
+        Pipeline p = createPipeline(pRS, s);
+
+        boolean pass = true;
+        // Four int steps ping-pong between two I32 buffers; the two float
+        // steps need two F32 buffers.
+        pass &= runOnce(pRS, s, p, 64, 32, 0, 4);
+        // A second run of the same shape reuses all of them.
+        pass &= runOnce(pRS, s, p, 64, 32, 1, 4);
+        // A new shape needs four more.
+        pass &= runOnce(pRS, s, p, 50, 20, 2, 8);
+        p.release();
+        pass &= (p.getAllocationCount() == 0);
+
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
@@ -18,6 +18,9 @@
  * pragmas before the functions (forward reference), and the other
  * test case exercises the pragmas after the functions (backward
  * reference).
+ *
+ * UT_reduce_backward.java is generated from this file by
+ * gen_reduce_backward.py; rerun it after changing this file.
  */
 
 package com.android.rs.test_compat;
@@ -27,6 +30,7 @@ import android.content.res.Resources;
 import android.support.v8.renderscript.*;
 import android.util.Log;
 import java.lang.Float;
+import java.util.Arrays;
 import java.util.Random;
 
 public class UT_reduce extends UnitTest {
@@ -67,7 +71,8 @@ public class UT_reduce extends UnitTest {
     }
 
     private <T extends Number> boolean result(String testName, T javaRslt, T rsRslt) {
-        final boolean success = javaRslt.equals(rsRslt);
+        final boolean success =
+                javaRslt.equals(rsRslt) & checkGolden(testName, rsRslt.toString());
         Log.i(TAG,
                 testName + ": java " + javaRslt + ", rs " + rsRslt + ": " +
                 (success ? "PASSED" : "FAILED"));
@@ -75,7 +80,9 @@ public class UT_reduce extends UnitTest {
     }
 
     private boolean result(String testName, Int2 javaRslt, Int2 rsRslt) {
-        final boolean success = (javaRslt.x == rsRslt.x) && (javaRslt.y == rsRslt.y);
+        final boolean success =
+                ((javaRslt.x == rsRslt.x) && (javaRslt.y == rsRslt.y)) &
+                checkGolden(testName, rsRslt.x + "," + rsRslt.y);
         Log.i(TAG,
                 testName +
                 ": java (" + javaRslt.x + ", " + javaRslt.y + ")" +
@@ -148,6 +155,52 @@ public class UT_reduce extends UnitTest {
         return result("findMinAndMax", javaRslt, rsRslt);
     }
 
+    //-----------------------------------------------------------------
+
+    // Like findMinAndMax(float[]), but of several equal minimum (or
+    // maximum) values picks the one with the lowest or highest index.
+    private Int2 findMinAndMax(float[] input, boolean highIdx) {
+        int minIdx = 0;
+        int maxIdx = 0;
+
+        for (int idx = 1; idx < input.length; ++idx) {
+            if ((input[idx] < input[minIdx]) || (highIdx && input[idx] == input[minIdx]))
+                minIdx = idx;
+            if ((input[idx] > input[maxIdx]) || (highIdx && input[idx] == input[maxIdx]))
+                maxIdx = idx;
+        }
+
+        return new Int2(minIdx, maxIdx);
+    }
+
+    // Few distinct values over a long input, so that the minimum and
+    // the maximum each occur many times, spread across every chunk.
+    private float[] createInputArrayFloatWithTies(int len, int seed) {
+        final int[] intArray = createInputArrayInt(len, seed, 64);
+        float[] array = new float[len];
+        for (int i = 0; i < len; ++i)
+            array[i] = intArray[i];
+        return array;
+    }
+
+    private boolean findMinAndMaxLowIdx(RenderScript RS, ScriptC_reduce s) {
+        final float[] input = createInputArrayFloatWithTies(100000, 15);
+
+        final Int2 javaRslt = findMinAndMax(input, false);
+        final Int2 rsRslt = s.reduce_findMinAndMaxLowIdx(input).get();
+
+        return result("findMinAndMaxLowIdx", javaRslt, rsRslt);
+    }
+
+    private boolean findMinAndMaxHighIdx(RenderScript RS, ScriptC_reduce s) {
+        final float[] input = createInputArrayFloatWithTies(100000, 16);
+
+        final Int2 javaRslt = findMinAndMax(input, true);
+        final Int2 rsRslt = s.reduce_findMinAndMaxHighIdx(input).get();
+
+        return result("findMinAndMaxHighIdx", javaRslt, rsRslt);
+    }
+
     ///////////////////////////////////////////////////////////////////
 
     private boolean fz(RenderScript RS, ScriptC_reduce s) {
@@ -158,6 +211,8 @@ public class UT_reduce extends UnitTest {
 
         final int rsRslt = s.reduce_fz(input).get();
 
+        // fz may find any zero cell, so the index it returns is not
+        // deterministic and is not checked against golden results.
         final boolean success = (input[rsRslt] == 0);
         Log.i(TAG,
                 "fz: input[" + rsRslt + "] == " + input[rsRslt] + ": " +
@@ -217,6 +272,50 @@ public class UT_reduce extends UnitTest {
 
     ///////////////////////////////////////////////////////////////////
 
+    // Exercises every subset of the x/y/z special arguments on one 3D
+    // input that has exactly one zero cell, so every kernel must report
+    // the coordinates of that cell.
+    private boolean specialArgs(RenderScript RS, ScriptC_reduce s) {
+        final int dimX = 31, dimY = 23, dimZ = 17;
+        final int inputLen = dimX * dimY * dimZ;
+
+        int[] inputArray = createInputArrayInt(inputLen, 13);
+        for (int i = 0; i < inputLen; ++i)
+            if (inputArray[i] == 0) inputArray[i] = 1;
+        final int zeroIdx = (new Random(14)).nextInt(inputLen);
+        inputArray[zeroIdx] = 0;
+        final int zeroX = zeroIdx % dimX;
+        final int zeroY = (zeroIdx / dimX) % dimY;
+        final int zeroZ = zeroIdx / (dimX * dimY);
+
+        Type.Builder typeBuilder = new Type.Builder(RS, Element.I32(RS));
+        typeBuilder.setX(dimX).setY(dimY).setZ(dimZ);
+        Allocation inputAllocation = Allocation.createTyped(RS, typeBuilder.create());
+        inputAllocation.copy3DRangeFrom(0, 0, 0, dimX, dimY, dimZ, inputArray);
+
+        boolean pass = true;
+        pass &= result("fzsX", zeroX, s.reduce_fzsX(inputAllocation).get());
+        pass &= result("fzsY", zeroY, s.reduce_fzsY(inputAllocation).get());
+        pass &= result("fzsZ", zeroZ, s.reduce_fzsZ(inputAllocation).get());
+        pass &= result("fzsXY", new Int2(zeroX, zeroY), s.reduce_fzsXY(inputAllocation).get());
+        pass &= result("fzsXZ", new Int2(zeroX, zeroZ), s.reduce_fzsXZ(inputAllocation).get());
+        pass &= result("fzsYZ", new Int2(zeroY, zeroZ), s.reduce_fzsYZ(inputAllocation).get());
+
+        final Int3 rsRslt = s.reduce_fzsXYZ(inputAllocation).get();
+        final boolean success =
+                (rsRslt.x == zeroX && rsRslt.y == zeroY && rsRslt.z == zeroZ) &
+                checkGolden("fzsXYZ", rsRslt.x + "," + rsRslt.y + "," + rsRslt.z);
+        Log.i(TAG,
+                "fzsXYZ: java (" + zeroX + ", " + zeroY + ", " + zeroZ + "), " +
+                "rs (" + rsRslt.x + ", " + rsRslt.y + ", " + rsRslt.z + "): " +
+                (success ? "PASSED" : "FAILED"));
+        pass &= success;
+
+        return pass;
+    }
+
+    ///////////////////////////////////////////////////////////////////
+
     private static final int histogramBucketCount = 256;
 
     private long[] histogram(RenderScript RS, final byte[] inputArray) {
@@ -254,6 +353,9 @@ public class UT_reduce extends UnitTest {
             }
         }
 
+        if (!checkGolden("histogram", Arrays.toString(rsRslt)))
+            return false;
+
         Log.i(TAG, "histogram: PASSED");
         return true;
     }
@@ -290,9 +392,12 @@ public class UT_reduce extends UnitTest {
         pass &= addint1D(pRS, s);
         pass &= addint2D(pRS, s);
         pass &= findMinAndMax(pRS, s);
+        pass &= findMinAndMaxLowIdx(pRS, s);
+        pass &= findMinAndMaxHighIdx(pRS, s);
         pass &= fz(pRS, s);
         pass &= fz2(pRS, s);
         pass &= fz3(pRS, s);
+        pass &= specialArgs(pRS, s);
         pass &= histogram(pRS, s);
         pass &= mode(pRS, s);
 
@@ -0,0 +1,48 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+
+public class UT_reduce_properties extends UnitTest {
+    private static final String TAG = "reduce_properties";
+    // A failing check logs this seed and the trial number, which are
+    // enough to reproduce its counterexample.
+    private static final int SEED = 0x203;
+
+    private Resources mRes;
+
+    protected UT_reduce_properties(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "reduce_properties", ctx);
+        mRes = res;
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_reduce_properties s = new ScriptC_reduce_properties(pRS);
+        pRS.setMessageHandler(mRsMessage);
+        Log.i(TAG, "seed " + SEED);
+        s.set_gSeed(SEED);
+        s.invoke_reduce_properties_test();
+        pRS.finish();
+        waitForMessage();
+        pRS.destroy();
+    }
+}
@@ -0,0 +1,1201 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Runs the sum, min, max and ffz reductions of reduce_types.rs for every
+ * scalar and vector element type (no sum for half) and checks them against
+ * Java references.
+ *
+ * This file is generated by gen_reduce_types.py.  Do not edit it by hand.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.os.Build;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Arrays;
+import java.util.Random;
+
+public class UT_reduce_types extends UnitTest {
+    private static final String TAG = "reduce_types";
+
+    // Cells per input allocation.  Prime, so that the input doesn't split
+    // evenly across worker threads.
+    private static final int INPUT_LEN = 10007;
+
+    private enum Kind { SIGNED, UNSIGNED, FLOAT }
+
+    protected UT_reduce_types(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "reduce_types", ctx);
+    }
+
+    ///////////////////////////////////////////////////////////////////
+    // Inputs
+    ///////////////////////////////////////////////////////////////////
+
+    // Brings v into the range of an integer type, the way a C conversion
+    // to that type would.
+    private static long normalize(long v, int bits, Kind kind) {
+        if (bits == 64)
+            return v;
+        if (kind == Kind.SIGNED)
+            return (v << (64 - bits)) >> (64 - bits);
+        return v & ((1L << bits) - 1);
+    }
+
+    private static long[] randomIntegers(Random rand, int vecSize, int bits, Kind kind, int limit) {
+        long[] array = new long[INPUT_LEN * vecSize];
+        for (int i = 0; i < array.length; ++i) {
+            if (limit == 0)
+                array[i] = normalize(rand.nextLong(), bits, kind);
+            else if (kind == Kind.SIGNED)
+                array[i] = rand.nextInt(2 * limit) - limit;
+            else
+                array[i] = rand.nextInt(limit);
+        }
+        return array;
+    }
+
+    // Small integral values, so that sums are exact in any order and half
+    // represents every value exactly.
+    private static double[] randomFloats(Random rand, int vecSize) {
+        double[] array = new double[INPUT_LEN * vecSize];
+        for (int i = 0; i < array.length; ++i)
+            array[i] = rand.nextInt(2001) - 1000;
+        return array;
+    }
+
+    // Zeroes two random cells, so that ffz has something to find.
+    private static void plantZeros(Random rand, long[] array, int vecSize) {
+        for (int n = 0; n < 2; ++n) {
+            final int cell = rand.nextInt(INPUT_LEN);
+            Arrays.fill(array, cell * vecSize, (cell + 1) * vecSize, 0);
+        }
+    }
+
+    private static void plantZeros(Random rand, double[] array, int vecSize) {
+        for (int n = 0; n < 2; ++n) {
+            final int cell = rand.nextInt(INPUT_LEN);
+            Arrays.fill(array, cell * vecSize, (cell + 1) * vecSize, 0);
+        }
+    }
+
+    private static byte[] toByteArray(long[] in) {
+        byte[] out = new byte[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = (byte) in[i];
+        return out;
+    }
+
+    private static short[] toShortArray(long[] in) {
+        short[] out = new short[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = (short) in[i];
+        return out;
+    }
+
+    private static int[] toIntArray(long[] in) {
+        int[] out = new int[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = (int) in[i];
+        return out;
+    }
+
+    private static long[] toLongArray(long[] in) {
+        return in;
+    }
+
+    // Bits of the half that exactly represents v, an integer with
+    // |v| < 2048.
+    private static short halfBits(double v) {
+        final int iv = (int) v;
+        if (iv == 0)
+            return 0;
+        final int sign = (iv < 0) ? 0x8000 : 0;
+        final int mag = Math.abs(iv);
+        final int exp = 31 - Integer.numberOfLeadingZeros(mag);
+        return (short) (sign | ((exp + 15) << 10) | ((mag << (10 - exp)) & 0x3ff));
+    }
+
+    private static short[] toHalfArray(double[] in) {
+        short[] out = new short[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = halfBits(in[i]);
+        return out;
+    }
+
+    // Raw half bits of each value, as values(short) etc. return them.
+    private static long[] halfBits(double[] in) {
+        long[] out = new long[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = halfBits(in[i]);
+        return out;
+    }
+
+    // Whether this device can run the half cases: RenderScript gained half
+    // in API 23, and an F16 allocation must round-trip raw bits through
+    // the support library's copy path.
+    private static boolean halfSupported(RenderScript RS) {
+        if (Build.VERSION.SDK_INT < Build.VERSION_CODES.M)
+            return false;
+        try {
+            Allocation alloc = Allocation.createSized(RS, Element.F16(RS), 1);
+            final short[] in = { halfBits(1.0) };
+            short[] out = new short[1];
+            alloc.copyFrom(in);
+            alloc.copyTo(out);
+            alloc.destroy();
+            return out[0] == in[0];
+        } catch (RSRuntimeException e) {
+            return false;
+        }
+    }
+
+    private static float[] toFloatArray(double[] in) {
+        float[] out = new float[in.length];
+        for (int i = 0; i < in.length; ++i) out[i] = (float) in[i];
+        return out;
+    }
+
+    private static double[] toDoubleArray(double[] in) {
+        return in;
+    }
+
+    private static Allocation createInput(RenderScript RS, Element elem, int vecSize) {
+        Allocation alloc = Allocation.createSized(RS, elem, INPUT_LEN);
+        if (vecSize == 3)
+            alloc.setAutoPadding(true);
+        return alloc;
+    }
+
+    ///////////////////////////////////////////////////////////////////
+    // Java references
+    ///////////////////////////////////////////////////////////////////
+
+    private static boolean less(long a, long b, Kind kind) {
+        if (kind == Kind.UNSIGNED)
+            return (a ^ Long.MIN_VALUE) < (b ^ Long.MIN_VALUE);
+        return a < b;
+    }
+
+    private static long[] sum(long[] in, int vecSize, int bits, Kind kind) {
+        long[] rslt = new long[vecSize];
+        for (int i = 0; i < in.length; ++i)
+            rslt[i % vecSize] += in[i];
+        for (int c = 0; c < vecSize; ++c)
+            rslt[c] = normalize(rslt[c], bits, kind);
+        return rslt;
+    }
+
+    private static long[] min(long[] in, int vecSize, Kind kind) {
+        long[] rslt = Arrays.copyOf(in, vecSize);
+        for (int i = vecSize; i < in.length; ++i)
+            if (less(in[i], rslt[i % vecSize], kind)) rslt[i % vecSize] = in[i];
+        return rslt;
+    }
+
+    private static long[] max(long[] in, int vecSize, Kind kind) {
+        long[] rslt = Arrays.copyOf(in, vecSize);
+        for (int i = vecSize; i < in.length; ++i)
+            if (less(rslt[i % vecSize], in[i], kind)) rslt[i % vecSize] = in[i];
+        return rslt;
+    }
+
+    private static double[] sum(double[] in, int vecSize) {
+        double[] rslt = new double[vecSize];
+        for (int i = 0; i < in.length; ++i)
+            rslt[i % vecSize] += in[i];
+        return rslt;
+    }
+
+    private static double[] min(double[] in, int vecSize) {
+        double[] rslt = Arrays.copyOf(in, vecSize);
+        for (int i = vecSize; i < in.length; ++i)
+            rslt[i % vecSize] = Math.min(rslt[i % vecSize], in[i]);
+        return rslt;
+    }
+
+    private static double[] max(double[] in, int vecSize) {
+        double[] rslt = Arrays.copyOf(in, vecSize);
+        for (int i = vecSize; i < in.length; ++i)
+            rslt[i % vecSize] = Math.max(rslt[i % vecSize], in[i]);
+        return rslt;
+    }
+
+    private static int firstZero(long[] in, int vecSize) {
+        for (int cell = 0; cell < INPUT_LEN; ++cell) {
+            boolean zero = true;
+            for (int c = 0; c < vecSize; ++c)
+                zero &= (in[cell * vecSize + c] == 0);
+            if (zero) return cell;
+        }
+        return -1;
+    }
+
+    private static int firstZero(double[] in, int vecSize) {
+        for (int cell = 0; cell < INPUT_LEN; ++cell) {
+            boolean zero = true;
+            for (int c = 0; c < vecSize; ++c)
+                zero &= (in[cell * vecSize + c] == 0);
+            if (zero) return cell;
+        }
+        return -1;
+    }
+
+    ///////////////////////////////////////////////////////////////////
+    // Reflected results as arrays of components
+    ///////////////////////////////////////////////////////////////////
+
+    private static long[] values(byte v) { return new long[] { v }; }
+    private static long[] values(short v) { return new long[] { v }; }
+    private static long[] values(int v) { return new long[] { v }; }
+    private static long[] values(long v) { return new long[] { v }; }
+    private static long[] values(Byte2 v) { return new long[] { v.x, v.y }; }
+    private static long[] values(Byte3 v) { return new long[] { v.x, v.y, v.z }; }
+    private static long[] values(Byte4 v) { return new long[] { v.x, v.y, v.z, v.w }; }
+    private static long[] values(Short2 v) { return new long[] { v.x, v.y }; }
+    private static long[] values(Short3 v) { return new long[] { v.x, v.y, v.z }; }
+    private static long[] values(Short4 v) { return new long[] { v.x, v.y, v.z, v.w }; }
+    private static long[] values(Int2 v) { return new long[] { v.x, v.y }; }
+    private static long[] values(Int3 v) { return new long[] { v.x, v.y, v.z }; }
+    private static long[] values(Int4 v) { return new long[] { v.x, v.y, v.z, v.w }; }
+    private static long[] values(Long2 v) { return new long[] { v.x, v.y }; }
+    private static long[] values(Long3 v) { return new long[] { v.x, v.y, v.z }; }
+    private static long[] values(Long4 v) { return new long[] { v.x, v.y, v.z, v.w }; }
+    private static double[] values(float v) { return new double[] { v }; }
+    private static double[] values(double v) { return new double[] { v }; }
+    private static double[] values(Float2 v) { return new double[] { v.x, v.y }; }
+    private static double[] values(Float3 v) { return new double[] { v.x, v.y, v.z }; }
+    private static double[] values(Float4 v) { return new double[] { v.x, v.y, v.z, v.w }; }
+    private static double[] values(Double2 v) { return new double[] { v.x, v.y }; }
+    private static double[] values(Double3 v) { return new double[] { v.x, v.y, v.z }; }
+    private static double[] values(Double4 v) { return new double[] { v.x, v.y, v.z, v.w }; }
+
+    private boolean result(String testName, String javaRslt, String rsRslt) {
+        final boolean success = javaRslt.equals(rsRslt);
+        Log.i(TAG,
+                testName + ": java " + javaRslt + ", rs " + rsRslt + ": " +
+                (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    private boolean result(String testName, long[] javaRslt, long[] rsRslt) {
+        return result(testName, Arrays.toString(javaRslt), Arrays.toString(rsRslt));
+    }
+
+    private boolean result(String testName, double[] javaRslt, double[] rsRslt) {
+        return result(testName, Arrays.toString(javaRslt), Arrays.toString(rsRslt));
+    }
+
+    private boolean result(String testName, int javaRslt, int rsRslt) {
+        return result(testName, Integer.toString(javaRslt), Integer.toString(rsRslt));
+    }
+
+    ///////////////////////////////////////////////////////////////////
+    // Generated test cases
+    ///////////////////////////////////////////////////////////////////
+
+    private boolean test_char(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 1, 8, Kind.SIGNED, 0);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.I8(RS), 1);
+        ain.copyFrom(toByteArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_char", sum(input, 1, 8, Kind.SIGNED),
+                values(s.reduce_sum_char(ain).get()));
+        pass &= result("min_char", min(input, 1, Kind.SIGNED),
+                values(s.reduce_min_char(ain).get()));
+        pass &= result("max_char", max(input, 1, Kind.SIGNED),
+                values(s.reduce_max_char(ain).get()));
+        pass &= result("ffz_char", firstZero(input, 1),
+                s.reduce_ffz_char(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_char2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 2, 8, Kind.SIGNED, 0);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.I8_2(RS), 2);
+        ain.copyFrom(toByteArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_char2", sum(input, 2, 8, Kind.SIGNED),
+                values(s.reduce_sum_char2(ain).get()));
+        pass &= result("min_char2", min(input, 2, Kind.SIGNED),
+                values(s.reduce_min_char2(ain).get()));
+        pass &= result("max_char2", max(input, 2, Kind.SIGNED),
+                values(s.reduce_max_char2(ain).get()));
+        pass &= result("ffz_char2", firstZero(input, 2),
+                s.reduce_ffz_char2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_char3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 3, 8, Kind.SIGNED, 0);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.I8_3(RS), 3);
+        ain.copyFrom(toByteArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_char3", sum(input, 3, 8, Kind.SIGNED),
+                values(s.reduce_sum_char3(ain).get()));
+        pass &= result("min_char3", min(input, 3, Kind.SIGNED),
+                values(s.reduce_min_char3(ain).get()));
+        pass &= result("max_char3", max(input, 3, Kind.SIGNED),
+                values(s.reduce_max_char3(ain).get()));
+        pass &= result("ffz_char3", firstZero(input, 3),
+                s.reduce_ffz_char3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_char4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 4, 8, Kind.SIGNED, 0);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.I8_4(RS), 4);
+        ain.copyFrom(toByteArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_char4", sum(input, 4, 8, Kind.SIGNED),
+                values(s.reduce_sum_char4(ain).get()));
+        pass &= result("min_char4", min(input, 4, Kind.SIGNED),
+                values(s.reduce_min_char4(ain).get()));
+        pass &= result("max_char4", max(input, 4, Kind.SIGNED),
+                values(s.reduce_max_char4(ain).get()));
+        pass &= result("ffz_char4", firstZero(input, 4),
+                s.reduce_ffz_char4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_uchar(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 1, 8, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.U8(RS), 1);
+        ain.copyFrom(toByteArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_uchar", sum(input, 1, 8, Kind.UNSIGNED),
+                values(s.reduce_sum_uchar(ain).get()));
+        pass &= result("min_uchar", min(input, 1, Kind.UNSIGNED),
+                values(s.reduce_min_uchar(ain).get()));
+        pass &= result("max_uchar", max(input, 1, Kind.UNSIGNED),
+                values(s.reduce_max_uchar(ain).get()));
+        pass &= result("ffz_uchar", firstZero(input, 1),
+                s.reduce_ffz_uchar(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_uchar2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 2, 8, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.U8_2(RS), 2);
+        ain.copyFrom(toByteArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_uchar2", sum(input, 2, 8, Kind.UNSIGNED),
+                values(s.reduce_sum_uchar2(ain).get()));
+        pass &= result("min_uchar2", min(input, 2, Kind.UNSIGNED),
+                values(s.reduce_min_uchar2(ain).get()));
+        pass &= result("max_uchar2", max(input, 2, Kind.UNSIGNED),
+                values(s.reduce_max_uchar2(ain).get()));
+        pass &= result("ffz_uchar2", firstZero(input, 2),
+                s.reduce_ffz_uchar2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_uchar3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 3, 8, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.U8_3(RS), 3);
+        ain.copyFrom(toByteArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_uchar3", sum(input, 3, 8, Kind.UNSIGNED),
+                values(s.reduce_sum_uchar3(ain).get()));
+        pass &= result("min_uchar3", min(input, 3, Kind.UNSIGNED),
+                values(s.reduce_min_uchar3(ain).get()));
+        pass &= result("max_uchar3", max(input, 3, Kind.UNSIGNED),
+                values(s.reduce_max_uchar3(ain).get()));
+        pass &= result("ffz_uchar3", firstZero(input, 3),
+                s.reduce_ffz_uchar3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_uchar4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 4, 8, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.U8_4(RS), 4);
+        ain.copyFrom(toByteArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_uchar4", sum(input, 4, 8, Kind.UNSIGNED),
+                values(s.reduce_sum_uchar4(ain).get()));
+        pass &= result("min_uchar4", min(input, 4, Kind.UNSIGNED),
+                values(s.reduce_min_uchar4(ain).get()));
+        pass &= result("max_uchar4", max(input, 4, Kind.UNSIGNED),
+                values(s.reduce_max_uchar4(ain).get()));
+        pass &= result("ffz_uchar4", firstZero(input, 4),
+                s.reduce_ffz_uchar4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_short(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 1, 16, Kind.SIGNED, 0);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.I16(RS), 1);
+        ain.copyFrom(toShortArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_short", sum(input, 1, 16, Kind.SIGNED),
+                values(s.reduce_sum_short(ain).get()));
+        pass &= result("min_short", min(input, 1, Kind.SIGNED),
+                values(s.reduce_min_short(ain).get()));
+        pass &= result("max_short", max(input, 1, Kind.SIGNED),
+                values(s.reduce_max_short(ain).get()));
+        pass &= result("ffz_short", firstZero(input, 1),
+                s.reduce_ffz_short(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_short2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 2, 16, Kind.SIGNED, 0);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.I16_2(RS), 2);
+        ain.copyFrom(toShortArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_short2", sum(input, 2, 16, Kind.SIGNED),
+                values(s.reduce_sum_short2(ain).get()));
+        pass &= result("min_short2", min(input, 2, Kind.SIGNED),
+                values(s.reduce_min_short2(ain).get()));
+        pass &= result("max_short2", max(input, 2, Kind.SIGNED),
+                values(s.reduce_max_short2(ain).get()));
+        pass &= result("ffz_short2", firstZero(input, 2),
+                s.reduce_ffz_short2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_short3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 3, 16, Kind.SIGNED, 0);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.I16_3(RS), 3);
+        ain.copyFrom(toShortArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_short3", sum(input, 3, 16, Kind.SIGNED),
+                values(s.reduce_sum_short3(ain).get()));
+        pass &= result("min_short3", min(input, 3, Kind.SIGNED),
+                values(s.reduce_min_short3(ain).get()));
+        pass &= result("max_short3", max(input, 3, Kind.SIGNED),
+                values(s.reduce_max_short3(ain).get()));
+        pass &= result("ffz_short3", firstZero(input, 3),
+                s.reduce_ffz_short3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_short4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 4, 16, Kind.SIGNED, 0);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.I16_4(RS), 4);
+        ain.copyFrom(toShortArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_short4", sum(input, 4, 16, Kind.SIGNED),
+                values(s.reduce_sum_short4(ain).get()));
+        pass &= result("min_short4", min(input, 4, Kind.SIGNED),
+                values(s.reduce_min_short4(ain).get()));
+        pass &= result("max_short4", max(input, 4, Kind.SIGNED),
+                values(s.reduce_max_short4(ain).get()));
+        pass &= result("ffz_short4", firstZero(input, 4),
+                s.reduce_ffz_short4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_ushort(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 1, 16, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.U16(RS), 1);
+        ain.copyFrom(toShortArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_ushort", sum(input, 1, 16, Kind.UNSIGNED),
+                values(s.reduce_sum_ushort(ain).get()));
+        pass &= result("min_ushort", min(input, 1, Kind.UNSIGNED),
+                values(s.reduce_min_ushort(ain).get()));
+        pass &= result("max_ushort", max(input, 1, Kind.UNSIGNED),
+                values(s.reduce_max_ushort(ain).get()));
+        pass &= result("ffz_ushort", firstZero(input, 1),
+                s.reduce_ffz_ushort(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_ushort2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 2, 16, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.U16_2(RS), 2);
+        ain.copyFrom(toShortArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_ushort2", sum(input, 2, 16, Kind.UNSIGNED),
+                values(s.reduce_sum_ushort2(ain).get()));
+        pass &= result("min_ushort2", min(input, 2, Kind.UNSIGNED),
+                values(s.reduce_min_ushort2(ain).get()));
+        pass &= result("max_ushort2", max(input, 2, Kind.UNSIGNED),
+                values(s.reduce_max_ushort2(ain).get()));
+        pass &= result("ffz_ushort2", firstZero(input, 2),
+                s.reduce_ffz_ushort2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_ushort3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 3, 16, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.U16_3(RS), 3);
+        ain.copyFrom(toShortArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_ushort3", sum(input, 3, 16, Kind.UNSIGNED),
+                values(s.reduce_sum_ushort3(ain).get()));
+        pass &= result("min_ushort3", min(input, 3, Kind.UNSIGNED),
+                values(s.reduce_min_ushort3(ain).get()));
+        pass &= result("max_ushort3", max(input, 3, Kind.UNSIGNED),
+                values(s.reduce_max_ushort3(ain).get()));
+        pass &= result("ffz_ushort3", firstZero(input, 3),
+                s.reduce_ffz_ushort3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_ushort4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 4, 16, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.U16_4(RS), 4);
+        ain.copyFrom(toShortArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_ushort4", sum(input, 4, 16, Kind.UNSIGNED),
+                values(s.reduce_sum_ushort4(ain).get()));
+        pass &= result("min_ushort4", min(input, 4, Kind.UNSIGNED),
+                values(s.reduce_min_ushort4(ain).get()));
+        pass &= result("max_ushort4", max(input, 4, Kind.UNSIGNED),
+                values(s.reduce_max_ushort4(ain).get()));
+        pass &= result("ffz_ushort4", firstZero(input, 4),
+                s.reduce_ffz_ushort4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_int(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 1, 32, Kind.SIGNED, 131072);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.I32(RS), 1);
+        ain.copyFrom(toIntArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_int", sum(input, 1, 32, Kind.SIGNED),
+                values(s.reduce_sum_int(ain).get()));
+        pass &= result("min_int", min(input, 1, Kind.SIGNED),
+                values(s.reduce_min_int(ain).get()));
+        pass &= result("max_int", max(input, 1, Kind.SIGNED),
+                values(s.reduce_max_int(ain).get()));
+        pass &= result("ffz_int", firstZero(input, 1),
+                s.reduce_ffz_int(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_int2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 2, 32, Kind.SIGNED, 131072);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.I32_2(RS), 2);
+        ain.copyFrom(toIntArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_int2", sum(input, 2, 32, Kind.SIGNED),
+                values(s.reduce_sum_int2(ain).get()));
+        pass &= result("min_int2", min(input, 2, Kind.SIGNED),
+                values(s.reduce_min_int2(ain).get()));
+        pass &= result("max_int2", max(input, 2, Kind.SIGNED),
+                values(s.reduce_max_int2(ain).get()));
+        pass &= result("ffz_int2", firstZero(input, 2),
+                s.reduce_ffz_int2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_int3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 3, 32, Kind.SIGNED, 131072);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.I32_3(RS), 3);
+        ain.copyFrom(toIntArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_int3", sum(input, 3, 32, Kind.SIGNED),
+                values(s.reduce_sum_int3(ain).get()));
+        pass &= result("min_int3", min(input, 3, Kind.SIGNED),
+                values(s.reduce_min_int3(ain).get()));
+        pass &= result("max_int3", max(input, 3, Kind.SIGNED),
+                values(s.reduce_max_int3(ain).get()));
+        pass &= result("ffz_int3", firstZero(input, 3),
+                s.reduce_ffz_int3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_int4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 4, 32, Kind.SIGNED, 131072);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.I32_4(RS), 4);
+        ain.copyFrom(toIntArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_int4", sum(input, 4, 32, Kind.SIGNED),
+                values(s.reduce_sum_int4(ain).get()));
+        pass &= result("min_int4", min(input, 4, Kind.SIGNED),
+                values(s.reduce_min_int4(ain).get()));
+        pass &= result("max_int4", max(input, 4, Kind.SIGNED),
+                values(s.reduce_max_int4(ain).get()));
+        pass &= result("ffz_int4", firstZero(input, 4),
+                s.reduce_ffz_int4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_uint(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 1, 32, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.U32(RS), 1);
+        ain.copyFrom(toIntArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_uint", sum(input, 1, 32, Kind.UNSIGNED),
+                values(s.reduce_sum_uint(ain).get()));
+        pass &= result("min_uint", min(input, 1, Kind.UNSIGNED),
+                values(s.reduce_min_uint(ain).get()));
+        pass &= result("max_uint", max(input, 1, Kind.UNSIGNED),
+                values(s.reduce_max_uint(ain).get()));
+        pass &= result("ffz_uint", firstZero(input, 1),
+                s.reduce_ffz_uint(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_uint2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 2, 32, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.U32_2(RS), 2);
+        ain.copyFrom(toIntArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_uint2", sum(input, 2, 32, Kind.UNSIGNED),
+                values(s.reduce_sum_uint2(ain).get()));
+        pass &= result("min_uint2", min(input, 2, Kind.UNSIGNED),
+                values(s.reduce_min_uint2(ain).get()));
+        pass &= result("max_uint2", max(input, 2, Kind.UNSIGNED),
+                values(s.reduce_max_uint2(ain).get()));
+        pass &= result("ffz_uint2", firstZero(input, 2),
+                s.reduce_ffz_uint2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_uint3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 3, 32, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.U32_3(RS), 3);
+        ain.copyFrom(toIntArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_uint3", sum(input, 3, 32, Kind.UNSIGNED),
+                values(s.reduce_sum_uint3(ain).get()));
+        pass &= result("min_uint3", min(input, 3, Kind.UNSIGNED),
+                values(s.reduce_min_uint3(ain).get()));
+        pass &= result("max_uint3", max(input, 3, Kind.UNSIGNED),
+                values(s.reduce_max_uint3(ain).get()));
+        pass &= result("ffz_uint3", firstZero(input, 3),
+                s.reduce_ffz_uint3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_uint4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 4, 32, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.U32_4(RS), 4);
+        ain.copyFrom(toIntArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_uint4", sum(input, 4, 32, Kind.UNSIGNED),
+                values(s.reduce_sum_uint4(ain).get()));
+        pass &= result("min_uint4", min(input, 4, Kind.UNSIGNED),
+                values(s.reduce_min_uint4(ain).get()));
+        pass &= result("max_uint4", max(input, 4, Kind.UNSIGNED),
+                values(s.reduce_max_uint4(ain).get()));
+        pass &= result("ffz_uint4", firstZero(input, 4),
+                s.reduce_ffz_uint4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_long(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 1, 64, Kind.SIGNED, 1048576);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.I64(RS), 1);
+        ain.copyFrom(toLongArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_long", sum(input, 1, 64, Kind.SIGNED),
+                values(s.reduce_sum_long(ain).get()));
+        pass &= result("min_long", min(input, 1, Kind.SIGNED),
+                values(s.reduce_min_long(ain).get()));
+        pass &= result("max_long", max(input, 1, Kind.SIGNED),
+                values(s.reduce_max_long(ain).get()));
+        pass &= result("ffz_long", firstZero(input, 1),
+                s.reduce_ffz_long(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_long2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 2, 64, Kind.SIGNED, 1048576);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.I64_2(RS), 2);
+        ain.copyFrom(toLongArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_long2", sum(input, 2, 64, Kind.SIGNED),
+                values(s.reduce_sum_long2(ain).get()));
+        pass &= result("min_long2", min(input, 2, Kind.SIGNED),
+                values(s.reduce_min_long2(ain).get()));
+        pass &= result("max_long2", max(input, 2, Kind.SIGNED),
+                values(s.reduce_max_long2(ain).get()));
+        pass &= result("ffz_long2", firstZero(input, 2),
+                s.reduce_ffz_long2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_long3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 3, 64, Kind.SIGNED, 1048576);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.I64_3(RS), 3);
+        ain.copyFrom(toLongArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_long3", sum(input, 3, 64, Kind.SIGNED),
+                values(s.reduce_sum_long3(ain).get()));
+        pass &= result("min_long3", min(input, 3, Kind.SIGNED),
+                values(s.reduce_min_long3(ain).get()));
+        pass &= result("max_long3", max(input, 3, Kind.SIGNED),
+                values(s.reduce_max_long3(ain).get()));
+        pass &= result("ffz_long3", firstZero(input, 3),
+                s.reduce_ffz_long3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_long4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 4, 64, Kind.SIGNED, 1048576);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.I64_4(RS), 4);
+        ain.copyFrom(toLongArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_long4", sum(input, 4, 64, Kind.SIGNED),
+                values(s.reduce_sum_long4(ain).get()));
+        pass &= result("min_long4", min(input, 4, Kind.SIGNED),
+                values(s.reduce_min_long4(ain).get()));
+        pass &= result("max_long4", max(input, 4, Kind.SIGNED),
+                values(s.reduce_max_long4(ain).get()));
+        pass &= result("ffz_long4", firstZero(input, 4),
+                s.reduce_ffz_long4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_ulong(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 1, 64, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.U64(RS), 1);
+        ain.copyFrom(toLongArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_ulong", sum(input, 1, 64, Kind.UNSIGNED),
+                values(s.reduce_sum_ulong(ain).get()));
+        pass &= result("min_ulong", min(input, 1, Kind.UNSIGNED),
+                values(s.reduce_min_ulong(ain).get()));
+        pass &= result("max_ulong", max(input, 1, Kind.UNSIGNED),
+                values(s.reduce_max_ulong(ain).get()));
+        pass &= result("ffz_ulong", firstZero(input, 1),
+                s.reduce_ffz_ulong(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_ulong2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 2, 64, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.U64_2(RS), 2);
+        ain.copyFrom(toLongArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_ulong2", sum(input, 2, 64, Kind.UNSIGNED),
+                values(s.reduce_sum_ulong2(ain).get()));
+        pass &= result("min_ulong2", min(input, 2, Kind.UNSIGNED),
+                values(s.reduce_min_ulong2(ain).get()));
+        pass &= result("max_ulong2", max(input, 2, Kind.UNSIGNED),
+                values(s.reduce_max_ulong2(ain).get()));
+        pass &= result("ffz_ulong2", firstZero(input, 2),
+                s.reduce_ffz_ulong2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_ulong3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 3, 64, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.U64_3(RS), 3);
+        ain.copyFrom(toLongArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_ulong3", sum(input, 3, 64, Kind.UNSIGNED),
+                values(s.reduce_sum_ulong3(ain).get()));
+        pass &= result("min_ulong3", min(input, 3, Kind.UNSIGNED),
+                values(s.reduce_min_ulong3(ain).get()));
+        pass &= result("max_ulong3", max(input, 3, Kind.UNSIGNED),
+                values(s.reduce_max_ulong3(ain).get()));
+        pass &= result("ffz_ulong3", firstZero(input, 3),
+                s.reduce_ffz_ulong3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_ulong4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final long[] input = randomIntegers(rand, 4, 64, Kind.UNSIGNED, 0);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.U64_4(RS), 4);
+        ain.copyFrom(toLongArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_ulong4", sum(input, 4, 64, Kind.UNSIGNED),
+                values(s.reduce_sum_ulong4(ain).get()));
+        pass &= result("min_ulong4", min(input, 4, Kind.UNSIGNED),
+                values(s.reduce_min_ulong4(ain).get()));
+        pass &= result("max_ulong4", max(input, 4, Kind.UNSIGNED),
+                values(s.reduce_max_ulong4(ain).get()));
+        pass &= result("ffz_ulong4", firstZero(input, 4),
+                s.reduce_ffz_ulong4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_half(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 1);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.F16(RS), 1);
+        ain.copyFrom(toHalfArray(input));
+
+        boolean pass = true;
+        pass &= result("min_half", halfBits(min(input, 1)),
+                values(s.reduce_min_half(ain).get()));
+        pass &= result("max_half", halfBits(max(input, 1)),
+                values(s.reduce_max_half(ain).get()));
+        pass &= result("ffz_half", firstZero(input, 1),
+                s.reduce_ffz_half(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_half2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 2);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.F16_2(RS), 2);
+        ain.copyFrom(toHalfArray(input));
+
+        boolean pass = true;
+        pass &= result("min_half2", halfBits(min(input, 2)),
+                values(s.reduce_min_half2(ain).get()));
+        pass &= result("max_half2", halfBits(max(input, 2)),
+                values(s.reduce_max_half2(ain).get()));
+        pass &= result("ffz_half2", firstZero(input, 2),
+                s.reduce_ffz_half2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_half3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 3);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.F16_3(RS), 3);
+        ain.copyFrom(toHalfArray(input));
+
+        boolean pass = true;
+        pass &= result("min_half3", halfBits(min(input, 3)),
+                values(s.reduce_min_half3(ain).get()));
+        pass &= result("max_half3", halfBits(max(input, 3)),
+                values(s.reduce_max_half3(ain).get()));
+        pass &= result("ffz_half3", firstZero(input, 3),
+                s.reduce_ffz_half3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_half4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 4);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.F16_4(RS), 4);
+        ain.copyFrom(toHalfArray(input));
+
+        boolean pass = true;
+        pass &= result("min_half4", halfBits(min(input, 4)),
+                values(s.reduce_min_half4(ain).get()));
+        pass &= result("max_half4", halfBits(max(input, 4)),
+                values(s.reduce_max_half4(ain).get()));
+        pass &= result("ffz_half4", firstZero(input, 4),
+                s.reduce_ffz_half4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_float(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 1);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.F32(RS), 1);
+        ain.copyFrom(toFloatArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_float", sum(input, 1),
+                values(s.reduce_sum_float(ain).get()));
+        pass &= result("min_float", min(input, 1),
+                values(s.reduce_min_float(ain).get()));
+        pass &= result("max_float", max(input, 1),
+                values(s.reduce_max_float(ain).get()));
+        pass &= result("ffz_float", firstZero(input, 1),
+                s.reduce_ffz_float(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_float2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 2);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.F32_2(RS), 2);
+        ain.copyFrom(toFloatArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_float2", sum(input, 2),
+                values(s.reduce_sum_float2(ain).get()));
+        pass &= result("min_float2", min(input, 2),
+                values(s.reduce_min_float2(ain).get()));
+        pass &= result("max_float2", max(input, 2),
+                values(s.reduce_max_float2(ain).get()));
+        pass &= result("ffz_float2", firstZero(input, 2),
+                s.reduce_ffz_float2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_float3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 3);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.F32_3(RS), 3);
+        ain.copyFrom(toFloatArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_float3", sum(input, 3),
+                values(s.reduce_sum_float3(ain).get()));
+        pass &= result("min_float3", min(input, 3),
+                values(s.reduce_min_float3(ain).get()));
+        pass &= result("max_float3", max(input, 3),
+                values(s.reduce_max_float3(ain).get()));
+        pass &= result("ffz_float3", firstZero(input, 3),
+                s.reduce_ffz_float3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_float4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 4);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.F32_4(RS), 4);
+        ain.copyFrom(toFloatArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_float4", sum(input, 4),
+                values(s.reduce_sum_float4(ain).get()));
+        pass &= result("min_float4", min(input, 4),
+                values(s.reduce_min_float4(ain).get()));
+        pass &= result("max_float4", max(input, 4),
+                values(s.reduce_max_float4(ain).get()));
+        pass &= result("ffz_float4", firstZero(input, 4),
+                s.reduce_ffz_float4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_double(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 1);
+        plantZeros(rand, input, 1);
+        Allocation ain = createInput(RS, Element.F64(RS), 1);
+        ain.copyFrom(toDoubleArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_double", sum(input, 1),
+                values(s.reduce_sum_double(ain).get()));
+        pass &= result("min_double", min(input, 1),
+                values(s.reduce_min_double(ain).get()));
+        pass &= result("max_double", max(input, 1),
+                values(s.reduce_max_double(ain).get()));
+        pass &= result("ffz_double", firstZero(input, 1),
+                s.reduce_ffz_double(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_double2(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 2);
+        plantZeros(rand, input, 2);
+        Allocation ain = createInput(RS, Element.F64_2(RS), 2);
+        ain.copyFrom(toDoubleArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_double2", sum(input, 2),
+                values(s.reduce_sum_double2(ain).get()));
+        pass &= result("min_double2", min(input, 2),
+                values(s.reduce_min_double2(ain).get()));
+        pass &= result("max_double2", max(input, 2),
+                values(s.reduce_max_double2(ain).get()));
+        pass &= result("ffz_double2", firstZero(input, 2),
+                s.reduce_ffz_double2(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_double3(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 3);
+        plantZeros(rand, input, 3);
+        Allocation ain = createInput(RS, Element.F64_3(RS), 3);
+        ain.copyFrom(toDoubleArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_double3", sum(input, 3),
+                values(s.reduce_sum_double3(ain).get()));
+        pass &= result("min_double3", min(input, 3),
+                values(s.reduce_min_double3(ain).get()));
+        pass &= result("max_double3", max(input, 3),
+                values(s.reduce_max_double3(ain).get()));
+        pass &= result("ffz_double3", firstZero(input, 3),
+                s.reduce_ffz_double3(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    private boolean test_double4(RenderScript RS, ScriptC_reduce_types s, Random rand) {
+        final double[] input = randomFloats(rand, 4);
+        plantZeros(rand, input, 4);
+        Allocation ain = createInput(RS, Element.F64_4(RS), 4);
+        ain.copyFrom(toDoubleArray(input));
+
+        boolean pass = true;
+        pass &= result("sum_double4", sum(input, 4),
+                values(s.reduce_sum_double4(ain).get()));
+        pass &= result("min_double4", min(input, 4),
+                values(s.reduce_min_double4(ain).get()));
+        pass &= result("max_double4", max(input, 4),
+                values(s.reduce_max_double4(ain).get()));
+        pass &= result("ffz_double4", firstZero(input, 4),
+                s.reduce_ffz_double4(ain).get());
+        ain.destroy();
+        return pass;
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_reduce_types s = new ScriptC_reduce_types(pRS);
+        Random rand = new Random(0x1e5a);
+
+        final boolean half = halfSupported(pRS);
+        if (!half)
+            Log.i(TAG, "half not supported here: skipping the half cases");
+
+        boolean pass = true;
+        pass &= test_char(pRS, s, rand);
+        pass &= test_char2(pRS, s, rand);
+        pass &= test_char3(pRS, s, rand);
+        pass &= test_char4(pRS, s, rand);
+        pass &= test_uchar(pRS, s, rand);
+        pass &= test_uchar2(pRS, s, rand);
+        pass &= test_uchar3(pRS, s, rand);
+        pass &= test_uchar4(pRS, s, rand);
+        pass &= test_short(pRS, s, rand);
+        pass &= test_short2(pRS, s, rand);
+        pass &= test_short3(pRS, s, rand);
+        pass &= test_short4(pRS, s, rand);
+        pass &= test_ushort(pRS, s, rand);
+        pass &= test_ushort2(pRS, s, rand);
+        pass &= test_ushort3(pRS, s, rand);
+        pass &= test_ushort4(pRS, s, rand);
+        pass &= test_int(pRS, s, rand);
+        pass &= test_int2(pRS, s, rand);
+        pass &= test_int3(pRS, s, rand);
+        pass &= test_int4(pRS, s, rand);
+        pass &= test_uint(pRS, s, rand);
+        pass &= test_uint2(pRS, s, rand);
+        pass &= test_uint3(pRS, s, rand);
+        pass &= test_uint4(pRS, s, rand);
+        pass &= test_long(pRS, s, rand);
+        pass &= test_long2(pRS, s, rand);
+        pass &= test_long3(pRS, s, rand);
+        pass &= test_long4(pRS, s, rand);
+        pass &= test_ulong(pRS, s, rand);
+        pass &= test_ulong2(pRS, s, rand);
+        pass &= test_ulong3(pRS, s, rand);
+        pass &= test_ulong4(pRS, s, rand);
+        if (half) {
+            pass &= test_half(pRS, s, rand);
+            pass &= test_half2(pRS, s, rand);
+            pass &= test_half3(pRS, s, rand);
+            pass &= test_half4(pRS, s, rand);
+        }
+        pass &= test_float(pRS, s, rand);
+        pass &= test_float2(pRS, s, rand);
+        pass &= test_float3(pRS, s, rand);
+        pass &= test_float4(pRS, s, rand);
+        pass &= test_double(pRS, s, rand);
+        pass &= test_double2(pRS, s, rand);
+        pass &= test_double3(pRS, s, rand);
+        pass &= test_double4(pRS, s, rand);
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
@@ -0,0 +1,183 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Checks that reductions over 3-vector inputs see exactly the x, y and z
+ * lanes of every cell, whichever way the input was filled: through the
+ * reflected array entry point, through an auto-padded Allocation (1D and
+ * 2D), or through an unpadded copy whose padding lane holds garbage.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+import java.util.Random;
+
+public class UT_reduce_vec3 extends UnitTest {
+    private static final String TAG = "reduce_vec3";
+
+    private static final int dimX = 251, dimY = 37;
+    private static final int cellCount = dimX * dimY;
+
+    protected UT_reduce_vec3(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "reduce_vec3", ctx);
+    }
+
+    // Three components per cell.
+    private int[] createPackedInt3(int seed) {
+        Random rand = new Random(seed);
+        int[] array = new int[cellCount * 3];
+        for (int i = 0; i < array.length; ++i)
+            array[i] = rand.nextInt(1 << 16) - (1 << 15);
+        return array;
+    }
+
+    private byte[] createPackedUchar3(int seed) {
+        byte[] array = new byte[cellCount * 3];
+        (new Random(seed)).nextBytes(array);
+        return array;
+    }
+
+    // Four components per cell, the fourth being garbage.
+    private int[] pad(int[] packed, int garbage) {
+        int[] padded = new int[cellCount * 4];
+        for (int cell = 0; cell < cellCount; ++cell) {
+            System.arraycopy(packed, cell * 3, padded, cell * 4, 3);
+            padded[cell * 4 + 3] = garbage;
+        }
+        return padded;
+    }
+
+    private byte[] pad(byte[] packed, byte garbage) {
+        byte[] padded = new byte[cellCount * 4];
+        for (int cell = 0; cell < cellCount; ++cell) {
+            System.arraycopy(packed, cell * 3, padded, cell * 4, 3);
+            padded[cell * 4 + 3] = garbage;
+        }
+        return padded;
+    }
+
+    private Int3 sumInt3(int[] packed) {
+        Int3 rslt = new Int3(0, 0, 0);
+        for (int cell = 0; cell < cellCount; ++cell) {
+            rslt.x += packed[cell * 3];
+            rslt.y += packed[cell * 3 + 1];
+            rslt.z += packed[cell * 3 + 2];
+        }
+        return rslt;
+    }
+
+    private Long3 sumUchar3(byte[] packed) {
+        Long3 rslt = new Long3(0, 0, 0);
+        for (int cell = 0; cell < cellCount; ++cell) {
+            rslt.x += packed[cell * 3] & 0xff;
+            rslt.y += packed[cell * 3 + 1] & 0xff;
+            rslt.z += packed[cell * 3 + 2] & 0xff;
+        }
+        return rslt;
+    }
+
+    private boolean result(String testName, Int3 javaRslt, Int3 rsRslt) {
+        final boolean success =
+                (javaRslt.x == rsRslt.x) && (javaRslt.y == rsRslt.y) && (javaRslt.z == rsRslt.z);
+        Log.i(TAG,
+                testName +
+                ": java (" + javaRslt.x + ", " + javaRslt.y + ", " + javaRslt.z + ")" +
+                ", rs (" + rsRslt.x + ", " + rsRslt.y + ", " + rsRslt.z + ")" +
+                ": " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    private boolean result(String testName, Long3 javaRslt, Long3 rsRslt) {
+        final boolean success =
+                (javaRslt.x == rsRslt.x) && (javaRslt.y == rsRslt.y) && (javaRslt.z == rsRslt.z);
+        Log.i(TAG,
+                testName +
+                ": java (" + javaRslt.x + ", " + javaRslt.y + ", " + javaRslt.z + ")" +
+                ", rs (" + rsRslt.x + ", " + rsRslt.y + ", " + rsRslt.z + ")" +
+                ": " + (success ? "PASSED" : "FAILED"));
+        return success;
+    }
+
+    ///////////////////////////////////////////////////////////////////
+
+    private boolean sumInt3(RenderScript RS, ScriptC_reduce_vec3 s) {
+        final int[] packed = createPackedInt3(0);
+        final Int3 javaRslt = sumInt3(packed);
+        boolean pass = true;
+
+        pass &= result("sumInt3Array", javaRslt, s.reduce_sumInt3(packed).get());
+
+        Allocation autoPadded = Allocation.createSized(RS, Element.I32_3(RS), cellCount);
+        autoPadded.setAutoPadding(true);
+        autoPadded.copyFrom(packed);
+        pass &= result("sumInt3AutoPadded", javaRslt, s.reduce_sumInt3(autoPadded).get());
+
+        Type.Builder typeBuilder = new Type.Builder(RS, Element.I32_3(RS));
+        typeBuilder.setX(dimX).setY(dimY);
+        Allocation autoPadded2D = Allocation.createTyped(RS, typeBuilder.create());
+        autoPadded2D.setAutoPadding(true);
+        autoPadded2D.copy2DRangeFrom(0, 0, dimX, dimY, packed);
+        pass &= result("sumInt3AutoPadded2D", javaRslt, s.reduce_sumInt3(autoPadded2D).get());
+
+        Allocation unpadded = Allocation.createSized(RS, Element.I32_3(RS), cellCount);
+        unpadded.copyFrom(pad(packed, 0x7f7f7f7f));
+        pass &= result("sumInt3GarbagePadding", javaRslt, s.reduce_sumInt3(unpadded).get());
+
+        return pass;
+    }
+
+    private boolean sumUchar3(RenderScript RS, ScriptC_reduce_vec3 s) {
+        final byte[] packed = createPackedUchar3(1);
+        final Long3 javaRslt = sumUchar3(packed);
+        boolean pass = true;
+
+        pass &= result("sumUchar3Array", javaRslt, s.reduce_sumUchar3(packed).get());
+
+        Allocation autoPadded = Allocation.createSized(RS, Element.U8_3(RS), cellCount);
+        autoPadded.setAutoPadding(true);
+        autoPadded.copyFrom(packed);
+        pass &= result("sumUchar3AutoPadded", javaRslt, s.reduce_sumUchar3(autoPadded).get());
+
+        Allocation unpadded = Allocation.createSized(RS, Element.U8_3(RS), cellCount);
+        unpadded.copyFrom(pad(packed, (byte) 0xff));
+        pass &= result("sumUchar3GarbagePadding", javaRslt, s.reduce_sumUchar3(unpadded).get());
+
+        return pass;
+    }
+
+    ///////////////////////////////////////////////////////////////////
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_reduce_vec3 s = new ScriptC_reduce_vec3(pRS);
+
+        boolean pass = true;
+        pass &= sumInt3(pRS, s);
+        pass &= sumUchar3(pRS, s);
+
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
@@ -0,0 +1,146 @@
+/*
+ * Copyright (C) 2016 The Android Open Source Project
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* Checks rng.rsh's seeded output, launch by launch, against a Java
+ * reference implementation of the same generator.
+ */
+
+package com.android.rs.test_compat;
+
+import android.content.Context;
+import android.content.res.Resources;
+import android.support.v8.renderscript.*;
+import android.util.Log;
+
+public class UT_rng extends UnitTest {
+    private static final String TAG = "rng";
+    private static final int DIM_X = 4099;
+    private static final int RANGE = 1000;  // as in rng.rs
+    private static final int STREAM_LEN = 3;  // as in rng.rs
+
+    protected UT_rng(RSTestCore rstc, Resources res, Context ctx) {
+        super(rstc, "rng", ctx);
+    }
+
+    // Reference implementation of rng.rsh.  Java int arithmetic wraps
+    // like uint32_t, and >>> is the unsigned shift.
+
+    private static int mix(int h) {
+        h ^= h >>> 16;
+        h *= 0x7feb352d;
+        h ^= h >>> 15;
+        h *= 0x846ca68b;
+        h ^= h >>> 16;
+        return h;
+    }
+
+    static int rngUint(int seed, int counter) {
+        return mix(mix(seed + 0x9e3779b9) + counter);
+    }
+
+    static int rngRange(int seed, int counter, int n) {
+        return (int) (((rngUint(seed, counter) & 0xffffffffL) * n) >>> 32);
+    }
+
+    static float rngFloat(int seed, int counter) {
+        return (rngUint(seed, counter) >>> 8) * (1.0f / 16777216.0f);
+    }
+
+    private static boolean check(String name, int seed, int[] rs, int[] java) {
+        for (int x = 0; x < rs.length; ++x) {
+            if (rs[x] != java[x]) {
+                Log.e(TAG, name + " seed " + seed + " x " + x +
+                        ": rs " + rs[x] + ", java " + java[x] + ": FAILED");
+                return false;
+            }
+        }
+        Log.i(TAG, name + " seed " + seed + ": PASSED");
+        return true;
+    }
+
+    private static boolean check(String name, int seed, float[] rs, float[] java) {
+        for (int x = 0; x < rs.length; ++x) {
+            if (rs[x] != java[x]) {
+                Log.e(TAG, name + " seed " + seed + " x " + x +
+                        ": rs " + rs[x] + ", java " + java[x] + ": FAILED");
+                return false;
+            }
+        }
+        Log.i(TAG, name + " seed " + seed + ": PASSED");
+        return true;
+    }
+
+    // One launch of each kernel with the given seed.
+    private boolean runSeed(ScriptC_rng s, Allocation outU32, Allocation outF32, int seed) {
+        s.set_gSeed(seed);
+
+        boolean pass = true;
+        int[] rs = new int[DIM_X];
+        int[] java = new int[DIM_X];
+
+        s.forEach_drawUint(outU32);
+        outU32.copyTo(rs);
+        for (int x = 0; x < DIM_X; ++x)
+            java[x] = rngUint(seed, x);
+        pass &= check("drawUint", seed, rs, java);
+
+        s.forEach_drawRange(outU32);
+        outU32.copyTo(rs);
+        for (int x = 0; x < DIM_X; ++x)
+            java[x] = rngRange(seed, x, RANGE);
+        pass &= check("drawRange", seed, rs, java);
+
+        s.forEach_drawStream(outU32);
+        outU32.copyTo(rs);
+        for (int x = 0; x < DIM_X; ++x)
+            java[x] = rngRange(seed, x * STREAM_LEN + STREAM_LEN - 1, RANGE);
+        pass &= check("drawStream", seed, rs, java);
+
+        float[] rsF = new float[DIM_X];
+        float[] javaF = new float[DIM_X];
+        s.forEach_drawFloat(outF32);
+        outF32.copyTo(rsF);
+        for (int x = 0; x < DIM_X; ++x)
+            javaF[x] = rngFloat(seed, x);
+        pass &= check("drawFloat", seed, rsF, javaF);
+
+        return pass;
+    }
+
+    public void run() {
+        RenderScript pRS = RenderScript.create(mCtx);
+        ScriptC_rng s = new ScriptC_rng(pRS);
+        Allocation outU32 = Allocation.createSized(pRS, Element.U32(pRS), DIM_X);
+        Allocation outF32 = Allocation.createSized(pRS, Element.F32(pRS), DIM_X);
+
+        boolean pass = true;
+        // Consecutive launches with different seeds, and a repeated seed
+        // after another one, which must reproduce its earlier output.
+        for (int seed : new int[] { 0, 1, 0x12345678, -1, 1 })
+            pass &= runSeed(s, outU32, outF32, seed);
+
+        outU32.destroy();
+        outF32.destroy();
+        pRS.finish();
+        pRS.destroy();
+
+        Log.i(TAG, pass ? "PASSED" : "FAILED");
+        if (pass)
+            passTest();
+        else
+            failTest();
+    }
+}
@@ -33,6 +33,10 @@ public class UT_rstime extends UnitTest {
         ScriptC_rstime s = new ScriptC_rstime(pRS);
         pRS.setMessageHandler(mRsMessage);
         s.setTimeZone("America/Los_Angeles");
+        Allocation stamps = Allocation.createSized(pRS, Element.I64(pRS), 64);
+        s.set_gStamps(stamps);
+        s.invoke_stamp_start();
+        s.forEach_stamp(stamps);
         s.invoke_test_rstime(0, 0);
         pRS.finish();
         waitForMessage();
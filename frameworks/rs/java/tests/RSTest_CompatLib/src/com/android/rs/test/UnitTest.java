@@ -25,6 +25,7 @@ public class UnitTest extends Thread {
     private ScriptField_ListAllocs_s.Item mItem;
     private RSTestCore mRSTC;
     private boolean msgHandled;
+    private GoldenResults mGolden;
     protected Context mCtx;
 
     /* These constants must match those in shared.rsh */
@@ -63,7 +64,26 @@ public class UnitTest extends Thread {
         }
     }
 
+    /* Checks a result that is expected to be deterministic against the
+     * golden file, when the harness runs in golden RECORD or REPLAY mode.
+     */
+    protected boolean checkGolden(String key, String value) {
+        if (mGolden == null) {
+            mGolden = (mRSTC != null) ? mRSTC.openGoldenResults(name)
+                    : new GoldenResults(GoldenResults.Mode.OFF, null, name);
+        }
+        if (!mGolden.check(key, value)) {
+            Log.e(name, "golden " + key + " FAILED");
+            return false;
+        }
+        return true;
+    }
+
     private void updateUI() {
+        if (mGolden != null && !mGolden.finish(result == 1)) {
+            Log.e(name, "golden results FAILED");
+            result = -1;
+        }
         if (mItem != null) {
             mItem.result = result;
             msgHandled = true;
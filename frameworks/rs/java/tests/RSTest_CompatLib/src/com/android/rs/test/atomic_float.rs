@@ -0,0 +1,59 @@
+#include "shared.rsh"
+#include "atomic_float.rsh"
+
+// Scatter-style accumulation: every cell of the input adds its value to
+// one of BINS global bins, chosen by its index, and to a single global
+// total, so that many threads contend for the same few locations.
+
+#define BINS 7
+
+float gBins[BINS];
+float gTotal;
+
+rs_allocation gResult;         // F32, BINS + 1 cells: the bins, then the total
+rs_allocation gHelpersFailed;  // I32, 1 cell
+
+void clear() {
+  for (int i = 0; i < BINS; ++i)
+    gBins[i] = 0;
+  gTotal = 0;
+}
+
+void scatter(const float *in, uint32_t x) {
+  atomicAdd(&gBins[x % BINS], *in);
+  atomicAdd(&gTotal, *in);
+}
+
+void getResult() {
+  for (int i = 0; i < BINS; ++i)
+    rsSetElementAt_float(gResult, gBins[i], i);
+  rsSetElementAt_float(gResult, gTotal, BINS);
+}
+
+static bool helpers_test() {
+  bool failed = false;
+
+  float v = 1.5f;
+  _RS_ASSERT(atomicAdd(&v, 2.f) == 1.5f);
+  _RS_ASSERT(v == 3.5f);
+  _RS_ASSERT(atomicAdd(&v, -3.5f) == 3.5f);
+  _RS_ASSERT(v == 0.f);
+
+  // +0 + -0 is +0, bit for bit.
+  AtomicFloatBits bits;
+  _RS_ASSERT(atomicAdd(&v, -0.f) == 0.f);
+  bits.f = v;
+  _RS_ASSERT(bits.u == 0);
+
+  float inf = posinf();
+  _RS_ASSERT(atomicAdd(&inf, 1.f) == posinf());
+  _RS_ASSERT(inf == posinf());
+
+  return failed;
+}
+
+void helpers_test_run() {
+  bool failed = helpers_test();
+  rsSetElementAt_int(gHelpersFailed, failed ? 1 : 0, 0);
+  rsDebug(failed ? "atomic_float helpers FAILED" : "atomic_float helpers PASSED", 0);
+}
@@ -0,0 +1,50 @@
+#include "shared.rsh"
+#include "complex.rsh"
+
+// Reductions over complex values: the total power of a spectrum, and
+// the correlation sum(a * conj(b)) of two signals.
+
+#pragma rs reduce(powerSum) \
+  accumulator(powerAccum) combiner(powerCombine)
+
+static void powerAccum(float *accum, float2 in) { *accum += cnorm(in); }
+
+static void powerCombine(float *accum, const float *other) { *accum += *other; }
+
+#pragma rs reduce(correlate) \
+  accumulator(corrAccum) combiner(corrCombine)
+
+static void corrAccum(float2 *accum, float2 a, float2 b) {
+  *accum = cadd(*accum, cmul(a, cconj(b)));
+}
+
+static void corrCombine(float2 *accum, const float2 *other) {
+  *accum = cadd(*accum, *other);
+}
+
+/////////////////////////////////////////////////////////////////////////
+
+rs_allocation gHelpersFailed;
+
+static bool helpers_test() {
+  bool failed = false;
+  const float2 a = {3.f, 4.f};
+  const float2 b = {1.f, -2.f};
+
+  float2 r = cadd(a, b);
+  _RS_ASSERT(r.x == 4.f && r.y == 2.f);
+  r = cmul(a, b);  // (3 + 4i)(1 - 2i) = 11 - 2i
+  _RS_ASSERT(r.x == 11.f && r.y == -2.f);
+  r = cmul(a, cconj(a));
+  _RS_ASSERT(r.x == 25.f && r.y == 0.f);
+  _RS_ASSERT(fabs(cabs(a) - 5.f) < 1e-5f);  // hypot may be off by an ulp
+  _RS_ASSERT(cnorm(b) == 5.f);
+
+  return failed;
+}
+
+void helpers_test_run() {
+  bool failed = helpers_test();
+  rsSetElementAt_int(gHelpersFailed, failed ? 1 : 0, 0);
+  rsDebug(failed ? "complex helpers FAILED" : "complex helpers PASSED", 0);
+}
@@ -0,0 +1,79 @@
+#include "shared.rsh"
+#include "fixed_point.rsh"
+
+// Deterministic sums of float inputs in Q16.16 and Q8.24.  Each input
+// is converted to fixed point, the raw values are summed exactly in an
+// int64_t, and the total is saturated once by the outconverter, so the
+// result is the same whatever order the runtime combines chunks in.
+
+#pragma rs reduce(sumQ16_16) \
+  accumulator(q16_16SumAccum) combiner(qSumCombine) \
+  outconverter(q16_16SumOut)
+
+#pragma rs reduce(sumQ8_24) \
+  accumulator(q8_24SumAccum) combiner(qSumCombine) \
+  outconverter(q8_24SumOut)
+
+static void q16_16SumAccum(int64_t *accum, float in) {
+  *accum += q16_16_fromFloat(in);
+}
+
+static void q8_24SumAccum(int64_t *accum, float in) {
+  *accum += q8_24_fromFloat(in);
+}
+
+static void qSumCombine(int64_t *accum, const int64_t *other) { *accum += *other; }
+
+static void q16_16SumOut(int *result, const int64_t *accum) {
+  *result = q16_16_saturate(*accum);
+}
+
+static void q8_24SumOut(int *result, const int64_t *accum) {
+  *result = q8_24_saturate(*accum);
+}
+
+/////////////////////////////////////////////////////////////////////////
+
+static bool helpers_test() {
+  bool failed = false;
+
+  _RS_ASSERT(q16_16_fromFloat(1.5f) == 0x18000);
+  _RS_ASSERT(q16_16_fromFloat(-1.f) == (int)0xffff0000);
+  _RS_ASSERT(q16_16_fromFloat(40000.f) == Q_MAX);
+  _RS_ASSERT(q16_16_fromFloat(-40000.f) == Q_MIN);
+  _RS_ASSERT(q16_16_toFloat(0x18000) == 1.5f);
+
+  _RS_ASSERT(q8_24_fromFloat(0.25f) == 0x400000);
+  _RS_ASSERT(q8_24_fromFloat(200.f) == Q_MAX);
+  _RS_ASSERT(q8_24_toFloat(0x400000) == 0.25f);
+
+  // halfway cases round to even
+  _RS_ASSERT(q16_16_fromFloat(1.5f / 65536.f) == 2);
+  _RS_ASSERT(q16_16_fromFloat(2.5f / 65536.f) == 2);
+
+  _RS_ASSERT(q16_16_addSat(q16_16_fromFloat(1.f), q16_16_fromFloat(2.f)) ==
+             q16_16_fromFloat(3.f));
+  _RS_ASSERT(q16_16_addSat(Q_MAX, 1) == Q_MAX);
+  _RS_ASSERT(q16_16_addSat(Q_MIN, -1) == Q_MIN);
+  _RS_ASSERT(q8_24_addSat(q8_24_fromFloat(100.f), q8_24_fromFloat(100.f)) == Q_MAX);
+
+  _RS_ASSERT(q16_16_mulSat(q16_16_fromFloat(2.f), q16_16_fromFloat(-3.f)) ==
+             q16_16_fromFloat(-6.f));
+  _RS_ASSERT(q16_16_mulSat(q16_16_fromFloat(300.f), q16_16_fromFloat(300.f)) == Q_MAX);
+  _RS_ASSERT(q16_16_mulSat(q16_16_fromFloat(300.f), q16_16_fromFloat(-300.f)) == Q_MIN);
+  _RS_ASSERT(q8_24_mulSat(q8_24_fromFloat(0.5f), q8_24_fromFloat(0.25f)) ==
+             q8_24_fromFloat(0.125f));
+
+  return failed;
+}
+
+// Set by the Java side to a 1-cell I32 allocation, which receives 1 if
+// any helper check failed and 0 otherwise; the Java side then reports
+// the test result once, together with its own checks of the sums.
+rs_allocation gHelpersFailed;
+
+void helpers_test_run() {
+  bool failed = helpers_test();
+  rsSetElementAt_int(gHelpersFailed, failed ? 1 : 0, 0);
+  rsDebug(failed ? "fixed_point helpers FAILED" : "fixed_point helpers PASSED", 0);
+}
@@ -0,0 +1,45 @@
+#include "shared.rsh"
+
+// Histogram equalization of a U8 image, as a sample of feeding a reduce
+// result into later kernels:
+//
+//   1. reduce_histogram() counts the pixels of each value.
+//   2. The Java side hands the result back through set_gHistogram(), and
+//      invoke_computeLut() turns its prefix sum (the CDF) into gLut.
+//   3. forEach_equalize() applies gLut to every pixel of the image.
+
+#include "reduce_histogram.rsh"
+
+#pragma rs reduce(histogram) \
+  accumulator(hsgAccum) combiner(hsgCombine)
+
+uint32_t gHistogram[BUCKETS];
+uchar gLut[BUCKETS];
+
+// Maps the lowest value present to 0 and the highest to 255, spacing
+// the others by their share of the pixels below them.  An image of a
+// single value maps to itself.
+void computeLut() {
+  uint32_t cdf[BUCKETS];
+  uint32_t sum = 0;
+  for (int i = 0; i < BUCKETS; ++i) {
+    sum += gHistogram[i];
+    cdf[i] = sum;
+  }
+
+  uint32_t cdfMin = 0;
+  for (int i = 0; i < BUCKETS && cdfMin == 0; ++i)
+    cdfMin = cdf[i];
+
+  const uint64_t denom = sum - cdfMin;
+  for (int i = 0; i < BUCKETS; ++i) {
+    if (denom == 0)
+      gLut[i] = i;
+    else if (cdf[i] < cdfMin)
+      gLut[i] = 0;
+    else
+      gLut[i] = (((uint64_t)(cdf[i] - cdfMin)) * 255 + denom / 2) / denom;
+  }
+}
+
+uchar RS_KERNEL equalize(uchar in) { return gLut[in]; }
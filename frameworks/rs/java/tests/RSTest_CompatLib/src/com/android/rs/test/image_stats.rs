@@ -0,0 +1,188 @@
+#include "shared.rsh"
+
+// One-pass image statistics, unpacked on the Java side by ImageStats.
+//
+// For every channel: minimum, maximum, 256-bucket histogram, and enough
+// to derive mean and variance.  U8 and U8_4 inputs keep exact integer
+// sums and sums of squares, so their results do not depend on how the
+// reduction is split.  F32 inputs keep a running mean and sum of squared
+// deviations, combined with Chan's pairwise update, and bucket values in
+// [0, 1) with values outside that range counted in the end buckets.
+// F32 inputs must not be NaN.
+
+#include "reduce_histogram.rsh"
+
+#define U8_CHANNEL_LEN (4 + BUCKETS)  // sum, sumSq, min, max, histogram
+#define F32_LEN (5 + BUCKETS)         // count, mean, m2, min, max, histogram
+
+// Reflected as ScriptC_image_stats.const_*, so that ImageStats.java uses
+// these sizes rather than repeating them.
+const int bucketCount = BUCKETS;
+const int u8ChannelLen = U8_CHANNEL_LEN;
+const int f32Len = F32_LEN;
+
+/////////////////////////////////////////////////////////////////////////
+
+typedef struct {
+  ulong sum, sumSq;
+  uint32_t min, max;
+  Histogram hist;
+} ChannelStatsU8;
+
+static void channelInit(ChannelStatsU8 *c) {
+  c->sum = c->sumSq = 0;
+  c->min = 255;
+  c->max = 0;
+  for (int i = 0; i < BUCKETS; ++i)
+    c->hist[i] = 0;
+}
+
+static void channelAccum(ChannelStatsU8 *c, uchar v) {
+  c->sum += v;
+  c->sumSq += (uint32_t)v * v;
+  c->min = min(c->min, (uint32_t)v);
+  c->max = max(c->max, (uint32_t)v);
+  hsgAccum(&c->hist, v);
+}
+
+static void channelCombine(ChannelStatsU8 *c, const ChannelStatsU8 *other) {
+  c->sum += other->sum;
+  c->sumSq += other->sumSq;
+  c->min = min(c->min, other->min);
+  c->max = max(c->max, other->max);
+  hsgCombine(&c->hist, &other->hist);
+}
+
+static void channelOut(ulong *out, const ChannelStatsU8 *c) {
+  out[0] = c->sum;
+  out[1] = c->sumSq;
+  out[2] = c->min;
+  out[3] = c->max;
+  for (int i = 0; i < BUCKETS; ++i)
+    out[4 + i] = c->hist[i];
+}
+
+/////////////////////////////////////////////////////////////////////////
+
+#pragma rs reduce(imageStatsU8) \
+  initializer(statsU8Init) accumulator(statsU8Accum) \
+  combiner(statsU8Combine) outconverter(statsU8Out)
+
+typedef struct {
+  ulong count;
+  ChannelStatsU8 c;
+} StatsU8;
+
+static void statsU8Init(StatsU8 *accum) {
+  accum->count = 0;
+  channelInit(&accum->c);
+}
+
+static void statsU8Accum(StatsU8 *accum, uchar in) {
+  ++accum->count;
+  channelAccum(&accum->c, in);
+}
+
+static void statsU8Combine(StatsU8 *accum, const StatsU8 *other) {
+  accum->count += other->count;
+  channelCombine(&accum->c, &other->c);
+}
+
+static void statsU8Out(ulong (*result)[1 + U8_CHANNEL_LEN], const StatsU8 *accum) {
+  (*result)[0] = accum->count;
+  channelOut(&(*result)[1], &accum->c);
+}
+
+/////////////////////////////////////////////////////////////////////////
+
+#pragma rs reduce(imageStatsU8_4) \
+  initializer(statsU8_4Init) accumulator(statsU8_4Accum) \
+  combiner(statsU8_4Combine) outconverter(statsU8_4Out)
+
+typedef struct {
+  ulong count;
+  ChannelStatsU8 c[4];
+} StatsU8_4;
+
+static void statsU8_4Init(StatsU8_4 *accum) {
+  accum->count = 0;
+  for (int ch = 0; ch < 4; ++ch)
+    channelInit(&accum->c[ch]);
+}
+
+static void statsU8_4Accum(StatsU8_4 *accum, uchar4 in) {
+  ++accum->count;
+  channelAccum(&accum->c[0], in.x);
+  channelAccum(&accum->c[1], in.y);
+  channelAccum(&accum->c[2], in.z);
+  channelAccum(&accum->c[3], in.w);
+}
+
+static void statsU8_4Combine(StatsU8_4 *accum, const StatsU8_4 *other) {
+  accum->count += other->count;
+  for (int ch = 0; ch < 4; ++ch)
+    channelCombine(&accum->c[ch], &other->c[ch]);
+}
+
+static void statsU8_4Out(ulong (*result)[1 + 4 * U8_CHANNEL_LEN], const StatsU8_4 *accum) {
+  (*result)[0] = accum->count;
+  for (int ch = 0; ch < 4; ++ch)
+    channelOut(&(*result)[1 + ch * U8_CHANNEL_LEN], &accum->c[ch]);
+}
+
+/////////////////////////////////////////////////////////////////////////
+
+#pragma rs reduce(imageStatsF32) \
+  initializer(statsF32Init) accumulator(statsF32Accum) \
+  combiner(statsF32Combine) outconverter(statsF32Out)
+
+typedef struct {
+  double count, mean, m2;
+  float min, max;
+  Histogram hist;
+} StatsF32;
+
+static void statsF32Init(StatsF32 *accum) {
+  accum->count = accum->mean = accum->m2 = 0;
+  accum->min = posinf();
+  accum->max = neginf();
+  for (int i = 0; i < BUCKETS; ++i)
+    accum->hist[i] = 0;
+}
+
+static void statsF32Accum(StatsF32 *accum, float in) {
+  accum->count += 1;
+  const double delta = in - accum->mean;
+  accum->mean += delta / accum->count;
+  accum->m2 += delta * (in - accum->mean);
+  accum->min = fmin(accum->min, in);
+  accum->max = fmax(accum->max, in);
+  hsgAccum(&accum->hist, (uchar)clamp(in * (float)BUCKETS, 0.f, (float)(BUCKETS - 1)));
+}
+
+static void statsF32Combine(StatsF32 *accum, const StatsF32 *other) {
+  if (other->count == 0)
+    return;
+  if (accum->count == 0) {
+    *accum = *other;
+    return;
+  }
+  const double count = accum->count + other->count;
+  const double delta = other->mean - accum->mean;
+  accum->mean += delta * other->count / count;
+  accum->m2 += other->m2 + delta * delta * accum->count * other->count / count;
+  accum->count = count;
+  accum->min = fmin(accum->min, other->min);
+  accum->max = fmax(accum->max, other->max);
+  hsgCombine(&accum->hist, &other->hist);
+}
+
+static void statsF32Out(double (*result)[F32_LEN], const StatsF32 *accum) {
+  (*result)[0] = accum->count;
+  (*result)[1] = accum->mean;
+  (*result)[2] = accum->m2;
+  (*result)[3] = accum->min;
+  (*result)[4] = accum->max;
+  for (int i = 0; i < BUCKETS; ++i)
+    (*result)[5 + i] = accum->hist[i];
+}
@@ -0,0 +1,91 @@
+#include "shared.rsh"
+
+// Checks that the element and dimension query builtins are enough to write
+// one helper that works on an allocation of any basic element type, by
+// branching on the type at runtime instead of duplicating the code for
+// every type.
+
+rs_allocation gAlloc;
+int gDimX;
+int gDimY;
+int gDimZ;
+int gDataType;
+int gVecSize;
+double gExpectedSum;
+static bool failed = false;
+
+// Sums every component of the cell at (x, y, z).  Captures the following
+// variables from the context where it gets instantiated:
+//     a, vecSize, x, y, z, sum
+#define SUM_CELL(RST, CT)                                                     \
+    case RST: {                                                               \
+        const CT *p = (const CT *) rsGetElementAt(a, x, y, z);                \
+        for (uint32_t i = 0; i < vecSize; i ++)                               \
+            sum += (double) p[i];                                             \
+        }                                                                     \
+        break;
+
+static double genericSum(rs_allocation a) {
+    rs_element e = rsAllocationGetElement(a);
+    rs_data_type dt = rsElementGetDataType(e);
+    uint32_t vecSize = rsElementGetVectorSize(e);
+
+    // Handle RenderScript's distinction between Y or Z dimension being absent
+    // and having a size of 1
+    uint32_t xEnd = rsAllocationGetDimX(a);
+    uint32_t yEnd = rsAllocationGetDimY(a) ? rsAllocationGetDimY(a) : 1;
+    uint32_t zEnd = rsAllocationGetDimZ(a) ? rsAllocationGetDimZ(a) : 1;
+
+    double sum = 0;
+    for (uint32_t z = 0; z < zEnd; z ++) {
+        for (uint32_t y = 0; y < yEnd; y ++) {
+            for (uint32_t x = 0; x < xEnd; x ++) {
+                switch (dt) {
+                    SUM_CELL(RS_TYPE_FLOAT_32, float);
+                    SUM_CELL(RS_TYPE_FLOAT_64, double);
+                    SUM_CELL(RS_TYPE_SIGNED_8, char);
+                    SUM_CELL(RS_TYPE_SIGNED_16, short);
+                    SUM_CELL(RS_TYPE_SIGNED_32, int);
+                    SUM_CELL(RS_TYPE_SIGNED_64, long);
+                    SUM_CELL(RS_TYPE_UNSIGNED_8, uchar);
+                    SUM_CELL(RS_TYPE_UNSIGNED_16, ushort);
+                    SUM_CELL(RS_TYPE_UNSIGNED_32, uint);
+                    SUM_CELL(RS_TYPE_UNSIGNED_64, ulong);
+                    default:
+                        // Invalid test
+                        _RS_ASSERT(false);
+                        return 0;
+                }
+            }
+        }
+    }
+    return sum;
+}
+
+void check_alloc() {
+    rs_element e = rsAllocationGetElement(gAlloc);
+
+    _RS_ASSERT(rsElementGetDataType(e) == (rs_data_type) gDataType);
+    _RS_ASSERT(rsElementGetVectorSize(e) == gVecSize);
+    _RS_ASSERT(rsAllocationGetDimX(gAlloc) == gDimX);
+    _RS_ASSERT(rsAllocationGetDimY(gAlloc) == gDimY);
+    _RS_ASSERT(rsAllocationGetDimZ(gAlloc) == gDimZ);
+
+    double sum = genericSum(gAlloc);
+    if (sum != gExpectedSum) {
+        failed = true;
+        rsDebug("genericSum", sum);
+        rsDebug("expected", gExpectedSum);
+    }
+}
+
+void introspection_test() {
+    if (failed) {
+        rsDebug("introspection_test FAILED", 0);
+        rsSendToClientBlocking(RS_MSG_TEST_FAILED);
+    }
+    else {
+        rsDebug("introspection_test PASSED", 0);
+        rsSendToClientBlocking(RS_MSG_TEST_PASSED);
+    }
+}
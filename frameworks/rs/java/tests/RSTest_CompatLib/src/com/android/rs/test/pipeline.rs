@@ -0,0 +1,15 @@
+#include "shared.rsh"
+
+// Kernels chained by UT_pipeline through Pipeline.java.
+
+int RS_KERNEL addOne(int in) { return in + 1; }
+
+int RS_KERNEL twice(int in) { return in * 2; }
+
+float RS_KERNEL toFloat(int in) { return (float)in; }
+
+float RS_KERNEL halve(float in) { return in * 0.5f; }
+
+#pragma rs reduce(sum) accumulator(sumAccum)
+
+static void sumAccum(float *accum, float in) { *accum += in; }
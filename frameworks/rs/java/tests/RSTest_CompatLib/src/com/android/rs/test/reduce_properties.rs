@@ -0,0 +1,148 @@
+#include "shared.rsh"
+
+// Runs the DEFINE_REDUCE_PROPERTY_CHECK checks over the reduce test
+// kernels in reduce_common.rsh, plus one deliberately broken kernel
+// that the checker must reject.
+
+#include "reduce_common.rsh"
+#include "reduce_properties.rsh"
+
+#define TRIALS 1000
+
+// Set from Java before reduce_properties_test() runs.
+uint32_t gSeed;
+
+/////////////////////////////////////////////////////////////////////////
+
+static void aiInit(int *accum) { *accum = 0; }
+
+static void aiAccumIdx(int *accum, int val, int x) { aiAccum(accum, val); }
+
+static void aiCombine(int *accum, const int *other) { *accum += *other; }
+
+static bool aiEquiv(const int *a, const int *b) { return *a == *b; }
+
+static int aiGen() { return rpRand(1 << 13); }
+
+DEFINE_REDUCE_PROPERTY_CHECK(addint, int, int,
+                             aiInit, aiAccumIdx, aiCombine, aiEquiv, aiGen)
+
+/////////////////////////////////////////////////////////////////////////
+
+// Few distinct values, so that ties are common.
+static float fMMGen() { return (float)rpRand(16); }
+
+// findMinAndMax leaves the index of a tied value unspecified.
+static bool fMMValueEquiv(const MinAndMax *a, const MinAndMax *b) {
+  return (a->min.val == b->min.val) && (a->max.val == b->max.val);
+}
+
+static bool fMMExactEquiv(const MinAndMax *a, const MinAndMax *b) {
+  return fMMValueEquiv(a, b) && (a->min.idx == b->min.idx) && (a->max.idx == b->max.idx);
+}
+
+DEFINE_REDUCE_PROPERTY_CHECK(findMinAndMax, MinAndMax, float,
+                             fMMInit, fMMAccumulator, fMMCombiner,
+                             fMMValueEquiv, fMMGen)
+
+DEFINE_REDUCE_PROPERTY_CHECK(findMinAndMaxLowIdx, MinAndMax, float,
+                             fMMInit, fMMLowAccumulator, fMMLowCombiner,
+                             fMMExactEquiv, fMMGen)
+
+DEFINE_REDUCE_PROPERTY_CHECK(findMinAndMaxHighIdx, MinAndMax, float,
+                             fMMInit, fMMHighAccumulator, fMMHighCombiner,
+                             fMMExactEquiv, fMMGen)
+
+/////////////////////////////////////////////////////////////////////////
+
+// fz may report any zero cell, so only whether one was found matters.
+static bool fzEquiv(const int *a, const int *b) { return (*a < 0) == (*b < 0); }
+
+static int fzGen() { return rpRand(4); }
+
+DEFINE_REDUCE_PROPERTY_CHECK(fz, int, int,
+                             fzInit, fzAccum, fzCombine, fzEquiv, fzGen)
+
+/////////////////////////////////////////////////////////////////////////
+
+typedef struct {
+  Histogram h;
+} HistogramBox;
+
+static void hsgBoxInit(HistogramBox *box) {
+  for (int i = 0; i < BUCKETS; ++i)
+    box->h[i] = 0;
+}
+
+static void hsgBoxAccum(HistogramBox *box, uchar in, int x) { hsgAccum(&box->h, in); }
+
+static void hsgBoxCombine(HistogramBox *box, const HistogramBox *other) {
+  hsgCombine(&box->h, &other->h);
+}
+
+static bool hsgBoxEquiv(const HistogramBox *a, const HistogramBox *b) {
+  for (int i = 0; i < BUCKETS; ++i)
+    if (a->h[i] != b->h[i]) return false;
+  return true;
+}
+
+static uchar hsgGen() { return (uchar)rpRand(BUCKETS); }
+
+DEFINE_REDUCE_PROPERTY_CHECK(histogram, HistogramBox, uchar,
+                             hsgBoxInit, hsgBoxAccum, hsgBoxCombine,
+                             hsgBoxEquiv, hsgGen)
+
+/////////////////////////////////////////////////////////////////////////
+
+// A running mean whose combiner averages the two means without
+// weighting them by their counts: wrong whenever the chunks differ in
+// size, including when one of them is empty.
+
+static void badMeanInit(float2 *accum) { accum->x = accum->y = 0; }
+
+static void badMeanAccum(float2 *accum, float in, int x) {
+  accum->x = (accum->x * accum->y + in) / (accum->y + 1);
+  accum->y += 1;
+}
+
+static void badMeanCombine(float2 *accum, const float2 *other) {
+  accum->x = (accum->x + other->x) / 2;
+  accum->y += other->y;
+}
+
+static bool badMeanEquiv(const float2 *a, const float2 *b) {
+  return (fabs(a->x - b->x) < 1e-3f) && (a->y == b->y);
+}
+
+static float badMeanGen() { return (float)(1 + rpRand(100)); }
+
+DEFINE_REDUCE_PROPERTY_CHECK(badMean, float2, float,
+                             badMeanInit, badMeanAccum, badMeanCombine,
+                             badMeanEquiv, badMeanGen)
+
+/////////////////////////////////////////////////////////////////////////
+
+void reduce_properties_test() {
+  bool failed = false;
+  negInf = neginf();
+  posInf = posinf();
+
+  _RS_ASSERT(addint_properties(gSeed, TRIALS, false));
+  _RS_ASSERT(findMinAndMax_properties(gSeed, TRIALS, false));
+  _RS_ASSERT(findMinAndMaxLowIdx_properties(gSeed, TRIALS, false));
+  _RS_ASSERT(findMinAndMaxHighIdx_properties(gSeed, TRIALS, false));
+  _RS_ASSERT(fz_properties(gSeed, TRIALS, false));
+  _RS_ASSERT(histogram_properties(gSeed, TRIALS, false));
+
+  // Must be rejected.
+  _RS_ASSERT(!badMean_properties(gSeed, TRIALS, true));
+
+  if (failed) {
+    rsSendToClientBlocking(RS_MSG_TEST_FAILED);
+    rsDebug("reduce_properties_test FAILED", -1);
+  }
+  else {
+    rsSendToClientBlocking(RS_MSG_TEST_PASSED);
+    rsDebug("reduce_properties_test PASSED", 0);
+  }
+}
@@ -0,0 +1,1890 @@
+#include "shared.rsh"
+
+// Reductions over every scalar and vector element type.
+//
+// This file is generated by gen_reduce_types.py.  Do not edit it by hand.
+
+// Shared by all ffz_* kernels: the accumulator is the lowest index found
+// so far, or -1.
+static void ffzInit(int *accum) { *accum = -1; }
+
+static void ffzCombine(int *accum, const int *other) {
+  if ((*other >= 0) && ((*accum < 0) || (*other < *accum)))
+    *accum = *other;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// char
+
+#pragma rs reduce(sum_char) accumulator(sum_char_accum)
+
+static void sum_char_accum(char *accum, char in) { *accum += in; }
+
+#pragma rs reduce(min_char) \
+  initializer(min_char_init) accumulator(min_char_accum)
+
+static void min_char_init(char *accum) {
+  *accum = 127;
+}
+
+static void min_char_accum(char *accum, char in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_char) \
+  initializer(max_char_init) accumulator(max_char_accum)
+
+static void max_char_init(char *accum) {
+  *accum = -128;
+}
+
+static void max_char_accum(char *accum, char in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_char) \
+  initializer(ffzInit) accumulator(ffz_char_accum) combiner(ffzCombine)
+
+static void ffz_char_accum(int *accum, char in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// char2
+
+#pragma rs reduce(sum_char2) accumulator(sum_char2_accum)
+
+static void sum_char2_accum(char2 *accum, char2 in) { *accum += in; }
+
+#pragma rs reduce(min_char2) \
+  initializer(min_char2_init) accumulator(min_char2_accum)
+
+static void min_char2_init(char2 *accum) {
+  accum->x = 127;
+  accum->y = 127;
+}
+
+static void min_char2_accum(char2 *accum, char2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_char2) \
+  initializer(max_char2_init) accumulator(max_char2_accum)
+
+static void max_char2_init(char2 *accum) {
+  accum->x = -128;
+  accum->y = -128;
+}
+
+static void max_char2_accum(char2 *accum, char2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_char2) \
+  initializer(ffzInit) accumulator(ffz_char2_accum) combiner(ffzCombine)
+
+static void ffz_char2_accum(int *accum, char2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// char3
+
+#pragma rs reduce(sum_char3) accumulator(sum_char3_accum)
+
+static void sum_char3_accum(char3 *accum, char3 in) { *accum += in; }
+
+#pragma rs reduce(min_char3) \
+  initializer(min_char3_init) accumulator(min_char3_accum)
+
+static void min_char3_init(char3 *accum) {
+  accum->x = 127;
+  accum->y = 127;
+  accum->z = 127;
+}
+
+static void min_char3_accum(char3 *accum, char3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_char3) \
+  initializer(max_char3_init) accumulator(max_char3_accum)
+
+static void max_char3_init(char3 *accum) {
+  accum->x = -128;
+  accum->y = -128;
+  accum->z = -128;
+}
+
+static void max_char3_accum(char3 *accum, char3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_char3) \
+  initializer(ffzInit) accumulator(ffz_char3_accum) combiner(ffzCombine)
+
+static void ffz_char3_accum(int *accum, char3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// char4
+
+#pragma rs reduce(sum_char4) accumulator(sum_char4_accum)
+
+static void sum_char4_accum(char4 *accum, char4 in) { *accum += in; }
+
+#pragma rs reduce(min_char4) \
+  initializer(min_char4_init) accumulator(min_char4_accum)
+
+static void min_char4_init(char4 *accum) {
+  accum->x = 127;
+  accum->y = 127;
+  accum->z = 127;
+  accum->w = 127;
+}
+
+static void min_char4_accum(char4 *accum, char4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_char4) \
+  initializer(max_char4_init) accumulator(max_char4_accum)
+
+static void max_char4_init(char4 *accum) {
+  accum->x = -128;
+  accum->y = -128;
+  accum->z = -128;
+  accum->w = -128;
+}
+
+static void max_char4_accum(char4 *accum, char4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_char4) \
+  initializer(ffzInit) accumulator(ffz_char4_accum) combiner(ffzCombine)
+
+static void ffz_char4_accum(int *accum, char4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// uchar
+
+#pragma rs reduce(sum_uchar) accumulator(sum_uchar_accum)
+
+static void sum_uchar_accum(uchar *accum, uchar in) { *accum += in; }
+
+#pragma rs reduce(min_uchar) \
+  initializer(min_uchar_init) accumulator(min_uchar_accum)
+
+static void min_uchar_init(uchar *accum) {
+  *accum = 255;
+}
+
+static void min_uchar_accum(uchar *accum, uchar in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_uchar) \
+  initializer(max_uchar_init) accumulator(max_uchar_accum)
+
+static void max_uchar_init(uchar *accum) {
+  *accum = 0;
+}
+
+static void max_uchar_accum(uchar *accum, uchar in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_uchar) \
+  initializer(ffzInit) accumulator(ffz_uchar_accum) combiner(ffzCombine)
+
+static void ffz_uchar_accum(int *accum, uchar in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// uchar2
+
+#pragma rs reduce(sum_uchar2) accumulator(sum_uchar2_accum)
+
+static void sum_uchar2_accum(uchar2 *accum, uchar2 in) { *accum += in; }
+
+#pragma rs reduce(min_uchar2) \
+  initializer(min_uchar2_init) accumulator(min_uchar2_accum)
+
+static void min_uchar2_init(uchar2 *accum) {
+  accum->x = 255;
+  accum->y = 255;
+}
+
+static void min_uchar2_accum(uchar2 *accum, uchar2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_uchar2) \
+  initializer(max_uchar2_init) accumulator(max_uchar2_accum)
+
+static void max_uchar2_init(uchar2 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+}
+
+static void max_uchar2_accum(uchar2 *accum, uchar2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_uchar2) \
+  initializer(ffzInit) accumulator(ffz_uchar2_accum) combiner(ffzCombine)
+
+static void ffz_uchar2_accum(int *accum, uchar2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// uchar3
+
+#pragma rs reduce(sum_uchar3) accumulator(sum_uchar3_accum)
+
+static void sum_uchar3_accum(uchar3 *accum, uchar3 in) { *accum += in; }
+
+#pragma rs reduce(min_uchar3) \
+  initializer(min_uchar3_init) accumulator(min_uchar3_accum)
+
+static void min_uchar3_init(uchar3 *accum) {
+  accum->x = 255;
+  accum->y = 255;
+  accum->z = 255;
+}
+
+static void min_uchar3_accum(uchar3 *accum, uchar3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_uchar3) \
+  initializer(max_uchar3_init) accumulator(max_uchar3_accum)
+
+static void max_uchar3_init(uchar3 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+  accum->z = 0;
+}
+
+static void max_uchar3_accum(uchar3 *accum, uchar3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_uchar3) \
+  initializer(ffzInit) accumulator(ffz_uchar3_accum) combiner(ffzCombine)
+
+static void ffz_uchar3_accum(int *accum, uchar3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// uchar4
+
+#pragma rs reduce(sum_uchar4) accumulator(sum_uchar4_accum)
+
+static void sum_uchar4_accum(uchar4 *accum, uchar4 in) { *accum += in; }
+
+#pragma rs reduce(min_uchar4) \
+  initializer(min_uchar4_init) accumulator(min_uchar4_accum)
+
+static void min_uchar4_init(uchar4 *accum) {
+  accum->x = 255;
+  accum->y = 255;
+  accum->z = 255;
+  accum->w = 255;
+}
+
+static void min_uchar4_accum(uchar4 *accum, uchar4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_uchar4) \
+  initializer(max_uchar4_init) accumulator(max_uchar4_accum)
+
+static void max_uchar4_init(uchar4 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+  accum->z = 0;
+  accum->w = 0;
+}
+
+static void max_uchar4_accum(uchar4 *accum, uchar4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_uchar4) \
+  initializer(ffzInit) accumulator(ffz_uchar4_accum) combiner(ffzCombine)
+
+static void ffz_uchar4_accum(int *accum, uchar4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// short
+
+#pragma rs reduce(sum_short) accumulator(sum_short_accum)
+
+static void sum_short_accum(short *accum, short in) { *accum += in; }
+
+#pragma rs reduce(min_short) \
+  initializer(min_short_init) accumulator(min_short_accum)
+
+static void min_short_init(short *accum) {
+  *accum = 32767;
+}
+
+static void min_short_accum(short *accum, short in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_short) \
+  initializer(max_short_init) accumulator(max_short_accum)
+
+static void max_short_init(short *accum) {
+  *accum = -32768;
+}
+
+static void max_short_accum(short *accum, short in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_short) \
+  initializer(ffzInit) accumulator(ffz_short_accum) combiner(ffzCombine)
+
+static void ffz_short_accum(int *accum, short in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// short2
+
+#pragma rs reduce(sum_short2) accumulator(sum_short2_accum)
+
+static void sum_short2_accum(short2 *accum, short2 in) { *accum += in; }
+
+#pragma rs reduce(min_short2) \
+  initializer(min_short2_init) accumulator(min_short2_accum)
+
+static void min_short2_init(short2 *accum) {
+  accum->x = 32767;
+  accum->y = 32767;
+}
+
+static void min_short2_accum(short2 *accum, short2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_short2) \
+  initializer(max_short2_init) accumulator(max_short2_accum)
+
+static void max_short2_init(short2 *accum) {
+  accum->x = -32768;
+  accum->y = -32768;
+}
+
+static void max_short2_accum(short2 *accum, short2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_short2) \
+  initializer(ffzInit) accumulator(ffz_short2_accum) combiner(ffzCombine)
+
+static void ffz_short2_accum(int *accum, short2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// short3
+
+#pragma rs reduce(sum_short3) accumulator(sum_short3_accum)
+
+static void sum_short3_accum(short3 *accum, short3 in) { *accum += in; }
+
+#pragma rs reduce(min_short3) \
+  initializer(min_short3_init) accumulator(min_short3_accum)
+
+static void min_short3_init(short3 *accum) {
+  accum->x = 32767;
+  accum->y = 32767;
+  accum->z = 32767;
+}
+
+static void min_short3_accum(short3 *accum, short3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_short3) \
+  initializer(max_short3_init) accumulator(max_short3_accum)
+
+static void max_short3_init(short3 *accum) {
+  accum->x = -32768;
+  accum->y = -32768;
+  accum->z = -32768;
+}
+
+static void max_short3_accum(short3 *accum, short3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_short3) \
+  initializer(ffzInit) accumulator(ffz_short3_accum) combiner(ffzCombine)
+
+static void ffz_short3_accum(int *accum, short3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// short4
+
+#pragma rs reduce(sum_short4) accumulator(sum_short4_accum)
+
+static void sum_short4_accum(short4 *accum, short4 in) { *accum += in; }
+
+#pragma rs reduce(min_short4) \
+  initializer(min_short4_init) accumulator(min_short4_accum)
+
+static void min_short4_init(short4 *accum) {
+  accum->x = 32767;
+  accum->y = 32767;
+  accum->z = 32767;
+  accum->w = 32767;
+}
+
+static void min_short4_accum(short4 *accum, short4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_short4) \
+  initializer(max_short4_init) accumulator(max_short4_accum)
+
+static void max_short4_init(short4 *accum) {
+  accum->x = -32768;
+  accum->y = -32768;
+  accum->z = -32768;
+  accum->w = -32768;
+}
+
+static void max_short4_accum(short4 *accum, short4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_short4) \
+  initializer(ffzInit) accumulator(ffz_short4_accum) combiner(ffzCombine)
+
+static void ffz_short4_accum(int *accum, short4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// ushort
+
+#pragma rs reduce(sum_ushort) accumulator(sum_ushort_accum)
+
+static void sum_ushort_accum(ushort *accum, ushort in) { *accum += in; }
+
+#pragma rs reduce(min_ushort) \
+  initializer(min_ushort_init) accumulator(min_ushort_accum)
+
+static void min_ushort_init(ushort *accum) {
+  *accum = 65535;
+}
+
+static void min_ushort_accum(ushort *accum, ushort in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_ushort) \
+  initializer(max_ushort_init) accumulator(max_ushort_accum)
+
+static void max_ushort_init(ushort *accum) {
+  *accum = 0;
+}
+
+static void max_ushort_accum(ushort *accum, ushort in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_ushort) \
+  initializer(ffzInit) accumulator(ffz_ushort_accum) combiner(ffzCombine)
+
+static void ffz_ushort_accum(int *accum, ushort in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// ushort2
+
+#pragma rs reduce(sum_ushort2) accumulator(sum_ushort2_accum)
+
+static void sum_ushort2_accum(ushort2 *accum, ushort2 in) { *accum += in; }
+
+#pragma rs reduce(min_ushort2) \
+  initializer(min_ushort2_init) accumulator(min_ushort2_accum)
+
+static void min_ushort2_init(ushort2 *accum) {
+  accum->x = 65535;
+  accum->y = 65535;
+}
+
+static void min_ushort2_accum(ushort2 *accum, ushort2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_ushort2) \
+  initializer(max_ushort2_init) accumulator(max_ushort2_accum)
+
+static void max_ushort2_init(ushort2 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+}
+
+static void max_ushort2_accum(ushort2 *accum, ushort2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_ushort2) \
+  initializer(ffzInit) accumulator(ffz_ushort2_accum) combiner(ffzCombine)
+
+static void ffz_ushort2_accum(int *accum, ushort2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// ushort3
+
+#pragma rs reduce(sum_ushort3) accumulator(sum_ushort3_accum)
+
+static void sum_ushort3_accum(ushort3 *accum, ushort3 in) { *accum += in; }
+
+#pragma rs reduce(min_ushort3) \
+  initializer(min_ushort3_init) accumulator(min_ushort3_accum)
+
+static void min_ushort3_init(ushort3 *accum) {
+  accum->x = 65535;
+  accum->y = 65535;
+  accum->z = 65535;
+}
+
+static void min_ushort3_accum(ushort3 *accum, ushort3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_ushort3) \
+  initializer(max_ushort3_init) accumulator(max_ushort3_accum)
+
+static void max_ushort3_init(ushort3 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+  accum->z = 0;
+}
+
+static void max_ushort3_accum(ushort3 *accum, ushort3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_ushort3) \
+  initializer(ffzInit) accumulator(ffz_ushort3_accum) combiner(ffzCombine)
+
+static void ffz_ushort3_accum(int *accum, ushort3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// ushort4
+
+#pragma rs reduce(sum_ushort4) accumulator(sum_ushort4_accum)
+
+static void sum_ushort4_accum(ushort4 *accum, ushort4 in) { *accum += in; }
+
+#pragma rs reduce(min_ushort4) \
+  initializer(min_ushort4_init) accumulator(min_ushort4_accum)
+
+static void min_ushort4_init(ushort4 *accum) {
+  accum->x = 65535;
+  accum->y = 65535;
+  accum->z = 65535;
+  accum->w = 65535;
+}
+
+static void min_ushort4_accum(ushort4 *accum, ushort4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_ushort4) \
+  initializer(max_ushort4_init) accumulator(max_ushort4_accum)
+
+static void max_ushort4_init(ushort4 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+  accum->z = 0;
+  accum->w = 0;
+}
+
+static void max_ushort4_accum(ushort4 *accum, ushort4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_ushort4) \
+  initializer(ffzInit) accumulator(ffz_ushort4_accum) combiner(ffzCombine)
+
+static void ffz_ushort4_accum(int *accum, ushort4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// int
+
+#pragma rs reduce(sum_int) accumulator(sum_int_accum)
+
+static void sum_int_accum(int *accum, int in) { *accum += in; }
+
+#pragma rs reduce(min_int) \
+  initializer(min_int_init) accumulator(min_int_accum)
+
+static void min_int_init(int *accum) {
+  *accum = 2147483647;
+}
+
+static void min_int_accum(int *accum, int in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_int) \
+  initializer(max_int_init) accumulator(max_int_accum)
+
+static void max_int_init(int *accum) {
+  *accum = (-2147483647 - 1);
+}
+
+static void max_int_accum(int *accum, int in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_int) \
+  initializer(ffzInit) accumulator(ffz_int_accum) combiner(ffzCombine)
+
+static void ffz_int_accum(int *accum, int in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// int2
+
+#pragma rs reduce(sum_int2) accumulator(sum_int2_accum)
+
+static void sum_int2_accum(int2 *accum, int2 in) { *accum += in; }
+
+#pragma rs reduce(min_int2) \
+  initializer(min_int2_init) accumulator(min_int2_accum)
+
+static void min_int2_init(int2 *accum) {
+  accum->x = 2147483647;
+  accum->y = 2147483647;
+}
+
+static void min_int2_accum(int2 *accum, int2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_int2) \
+  initializer(max_int2_init) accumulator(max_int2_accum)
+
+static void max_int2_init(int2 *accum) {
+  accum->x = (-2147483647 - 1);
+  accum->y = (-2147483647 - 1);
+}
+
+static void max_int2_accum(int2 *accum, int2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_int2) \
+  initializer(ffzInit) accumulator(ffz_int2_accum) combiner(ffzCombine)
+
+static void ffz_int2_accum(int *accum, int2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// int3
+
+#pragma rs reduce(sum_int3) accumulator(sum_int3_accum)
+
+static void sum_int3_accum(int3 *accum, int3 in) { *accum += in; }
+
+#pragma rs reduce(min_int3) \
+  initializer(min_int3_init) accumulator(min_int3_accum)
+
+static void min_int3_init(int3 *accum) {
+  accum->x = 2147483647;
+  accum->y = 2147483647;
+  accum->z = 2147483647;
+}
+
+static void min_int3_accum(int3 *accum, int3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_int3) \
+  initializer(max_int3_init) accumulator(max_int3_accum)
+
+static void max_int3_init(int3 *accum) {
+  accum->x = (-2147483647 - 1);
+  accum->y = (-2147483647 - 1);
+  accum->z = (-2147483647 - 1);
+}
+
+static void max_int3_accum(int3 *accum, int3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_int3) \
+  initializer(ffzInit) accumulator(ffz_int3_accum) combiner(ffzCombine)
+
+static void ffz_int3_accum(int *accum, int3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// int4
+
+#pragma rs reduce(sum_int4) accumulator(sum_int4_accum)
+
+static void sum_int4_accum(int4 *accum, int4 in) { *accum += in; }
+
+#pragma rs reduce(min_int4) \
+  initializer(min_int4_init) accumulator(min_int4_accum)
+
+static void min_int4_init(int4 *accum) {
+  accum->x = 2147483647;
+  accum->y = 2147483647;
+  accum->z = 2147483647;
+  accum->w = 2147483647;
+}
+
+static void min_int4_accum(int4 *accum, int4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_int4) \
+  initializer(max_int4_init) accumulator(max_int4_accum)
+
+static void max_int4_init(int4 *accum) {
+  accum->x = (-2147483647 - 1);
+  accum->y = (-2147483647 - 1);
+  accum->z = (-2147483647 - 1);
+  accum->w = (-2147483647 - 1);
+}
+
+static void max_int4_accum(int4 *accum, int4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_int4) \
+  initializer(ffzInit) accumulator(ffz_int4_accum) combiner(ffzCombine)
+
+static void ffz_int4_accum(int *accum, int4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// uint
+
+#pragma rs reduce(sum_uint) accumulator(sum_uint_accum)
+
+static void sum_uint_accum(uint *accum, uint in) { *accum += in; }
+
+#pragma rs reduce(min_uint) \
+  initializer(min_uint_init) accumulator(min_uint_accum)
+
+static void min_uint_init(uint *accum) {
+  *accum = 4294967295U;
+}
+
+static void min_uint_accum(uint *accum, uint in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_uint) \
+  initializer(max_uint_init) accumulator(max_uint_accum)
+
+static void max_uint_init(uint *accum) {
+  *accum = 0;
+}
+
+static void max_uint_accum(uint *accum, uint in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_uint) \
+  initializer(ffzInit) accumulator(ffz_uint_accum) combiner(ffzCombine)
+
+static void ffz_uint_accum(int *accum, uint in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// uint2
+
+#pragma rs reduce(sum_uint2) accumulator(sum_uint2_accum)
+
+static void sum_uint2_accum(uint2 *accum, uint2 in) { *accum += in; }
+
+#pragma rs reduce(min_uint2) \
+  initializer(min_uint2_init) accumulator(min_uint2_accum)
+
+static void min_uint2_init(uint2 *accum) {
+  accum->x = 4294967295U;
+  accum->y = 4294967295U;
+}
+
+static void min_uint2_accum(uint2 *accum, uint2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_uint2) \
+  initializer(max_uint2_init) accumulator(max_uint2_accum)
+
+static void max_uint2_init(uint2 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+}
+
+static void max_uint2_accum(uint2 *accum, uint2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_uint2) \
+  initializer(ffzInit) accumulator(ffz_uint2_accum) combiner(ffzCombine)
+
+static void ffz_uint2_accum(int *accum, uint2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// uint3
+
+#pragma rs reduce(sum_uint3) accumulator(sum_uint3_accum)
+
+static void sum_uint3_accum(uint3 *accum, uint3 in) { *accum += in; }
+
+#pragma rs reduce(min_uint3) \
+  initializer(min_uint3_init) accumulator(min_uint3_accum)
+
+static void min_uint3_init(uint3 *accum) {
+  accum->x = 4294967295U;
+  accum->y = 4294967295U;
+  accum->z = 4294967295U;
+}
+
+static void min_uint3_accum(uint3 *accum, uint3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_uint3) \
+  initializer(max_uint3_init) accumulator(max_uint3_accum)
+
+static void max_uint3_init(uint3 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+  accum->z = 0;
+}
+
+static void max_uint3_accum(uint3 *accum, uint3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_uint3) \
+  initializer(ffzInit) accumulator(ffz_uint3_accum) combiner(ffzCombine)
+
+static void ffz_uint3_accum(int *accum, uint3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// uint4
+
+#pragma rs reduce(sum_uint4) accumulator(sum_uint4_accum)
+
+static void sum_uint4_accum(uint4 *accum, uint4 in) { *accum += in; }
+
+#pragma rs reduce(min_uint4) \
+  initializer(min_uint4_init) accumulator(min_uint4_accum)
+
+static void min_uint4_init(uint4 *accum) {
+  accum->x = 4294967295U;
+  accum->y = 4294967295U;
+  accum->z = 4294967295U;
+  accum->w = 4294967295U;
+}
+
+static void min_uint4_accum(uint4 *accum, uint4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_uint4) \
+  initializer(max_uint4_init) accumulator(max_uint4_accum)
+
+static void max_uint4_init(uint4 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+  accum->z = 0;
+  accum->w = 0;
+}
+
+static void max_uint4_accum(uint4 *accum, uint4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_uint4) \
+  initializer(ffzInit) accumulator(ffz_uint4_accum) combiner(ffzCombine)
+
+static void ffz_uint4_accum(int *accum, uint4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// long
+
+#pragma rs reduce(sum_long) accumulator(sum_long_accum)
+
+static void sum_long_accum(long *accum, long in) { *accum += in; }
+
+#pragma rs reduce(min_long) \
+  initializer(min_long_init) accumulator(min_long_accum)
+
+static void min_long_init(long *accum) {
+  *accum = 9223372036854775807L;
+}
+
+static void min_long_accum(long *accum, long in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_long) \
+  initializer(max_long_init) accumulator(max_long_accum)
+
+static void max_long_init(long *accum) {
+  *accum = (-9223372036854775807L - 1);
+}
+
+static void max_long_accum(long *accum, long in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_long) \
+  initializer(ffzInit) accumulator(ffz_long_accum) combiner(ffzCombine)
+
+static void ffz_long_accum(int *accum, long in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// long2
+
+#pragma rs reduce(sum_long2) accumulator(sum_long2_accum)
+
+static void sum_long2_accum(long2 *accum, long2 in) { *accum += in; }
+
+#pragma rs reduce(min_long2) \
+  initializer(min_long2_init) accumulator(min_long2_accum)
+
+static void min_long2_init(long2 *accum) {
+  accum->x = 9223372036854775807L;
+  accum->y = 9223372036854775807L;
+}
+
+static void min_long2_accum(long2 *accum, long2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_long2) \
+  initializer(max_long2_init) accumulator(max_long2_accum)
+
+static void max_long2_init(long2 *accum) {
+  accum->x = (-9223372036854775807L - 1);
+  accum->y = (-9223372036854775807L - 1);
+}
+
+static void max_long2_accum(long2 *accum, long2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_long2) \
+  initializer(ffzInit) accumulator(ffz_long2_accum) combiner(ffzCombine)
+
+static void ffz_long2_accum(int *accum, long2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// long3
+
+#pragma rs reduce(sum_long3) accumulator(sum_long3_accum)
+
+static void sum_long3_accum(long3 *accum, long3 in) { *accum += in; }
+
+#pragma rs reduce(min_long3) \
+  initializer(min_long3_init) accumulator(min_long3_accum)
+
+static void min_long3_init(long3 *accum) {
+  accum->x = 9223372036854775807L;
+  accum->y = 9223372036854775807L;
+  accum->z = 9223372036854775807L;
+}
+
+static void min_long3_accum(long3 *accum, long3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_long3) \
+  initializer(max_long3_init) accumulator(max_long3_accum)
+
+static void max_long3_init(long3 *accum) {
+  accum->x = (-9223372036854775807L - 1);
+  accum->y = (-9223372036854775807L - 1);
+  accum->z = (-9223372036854775807L - 1);
+}
+
+static void max_long3_accum(long3 *accum, long3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_long3) \
+  initializer(ffzInit) accumulator(ffz_long3_accum) combiner(ffzCombine)
+
+static void ffz_long3_accum(int *accum, long3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// long4
+
+#pragma rs reduce(sum_long4) accumulator(sum_long4_accum)
+
+static void sum_long4_accum(long4 *accum, long4 in) { *accum += in; }
+
+#pragma rs reduce(min_long4) \
+  initializer(min_long4_init) accumulator(min_long4_accum)
+
+static void min_long4_init(long4 *accum) {
+  accum->x = 9223372036854775807L;
+  accum->y = 9223372036854775807L;
+  accum->z = 9223372036854775807L;
+  accum->w = 9223372036854775807L;
+}
+
+static void min_long4_accum(long4 *accum, long4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_long4) \
+  initializer(max_long4_init) accumulator(max_long4_accum)
+
+static void max_long4_init(long4 *accum) {
+  accum->x = (-9223372036854775807L - 1);
+  accum->y = (-9223372036854775807L - 1);
+  accum->z = (-9223372036854775807L - 1);
+  accum->w = (-9223372036854775807L - 1);
+}
+
+static void max_long4_accum(long4 *accum, long4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_long4) \
+  initializer(ffzInit) accumulator(ffz_long4_accum) combiner(ffzCombine)
+
+static void ffz_long4_accum(int *accum, long4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// ulong
+
+#pragma rs reduce(sum_ulong) accumulator(sum_ulong_accum)
+
+static void sum_ulong_accum(ulong *accum, ulong in) { *accum += in; }
+
+#pragma rs reduce(min_ulong) \
+  initializer(min_ulong_init) accumulator(min_ulong_accum)
+
+static void min_ulong_init(ulong *accum) {
+  *accum = 18446744073709551615UL;
+}
+
+static void min_ulong_accum(ulong *accum, ulong in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_ulong) \
+  initializer(max_ulong_init) accumulator(max_ulong_accum)
+
+static void max_ulong_init(ulong *accum) {
+  *accum = 0;
+}
+
+static void max_ulong_accum(ulong *accum, ulong in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_ulong) \
+  initializer(ffzInit) accumulator(ffz_ulong_accum) combiner(ffzCombine)
+
+static void ffz_ulong_accum(int *accum, ulong in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// ulong2
+
+#pragma rs reduce(sum_ulong2) accumulator(sum_ulong2_accum)
+
+static void sum_ulong2_accum(ulong2 *accum, ulong2 in) { *accum += in; }
+
+#pragma rs reduce(min_ulong2) \
+  initializer(min_ulong2_init) accumulator(min_ulong2_accum)
+
+static void min_ulong2_init(ulong2 *accum) {
+  accum->x = 18446744073709551615UL;
+  accum->y = 18446744073709551615UL;
+}
+
+static void min_ulong2_accum(ulong2 *accum, ulong2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_ulong2) \
+  initializer(max_ulong2_init) accumulator(max_ulong2_accum)
+
+static void max_ulong2_init(ulong2 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+}
+
+static void max_ulong2_accum(ulong2 *accum, ulong2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_ulong2) \
+  initializer(ffzInit) accumulator(ffz_ulong2_accum) combiner(ffzCombine)
+
+static void ffz_ulong2_accum(int *accum, ulong2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// ulong3
+
+#pragma rs reduce(sum_ulong3) accumulator(sum_ulong3_accum)
+
+static void sum_ulong3_accum(ulong3 *accum, ulong3 in) { *accum += in; }
+
+#pragma rs reduce(min_ulong3) \
+  initializer(min_ulong3_init) accumulator(min_ulong3_accum)
+
+static void min_ulong3_init(ulong3 *accum) {
+  accum->x = 18446744073709551615UL;
+  accum->y = 18446744073709551615UL;
+  accum->z = 18446744073709551615UL;
+}
+
+static void min_ulong3_accum(ulong3 *accum, ulong3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_ulong3) \
+  initializer(max_ulong3_init) accumulator(max_ulong3_accum)
+
+static void max_ulong3_init(ulong3 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+  accum->z = 0;
+}
+
+static void max_ulong3_accum(ulong3 *accum, ulong3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_ulong3) \
+  initializer(ffzInit) accumulator(ffz_ulong3_accum) combiner(ffzCombine)
+
+static void ffz_ulong3_accum(int *accum, ulong3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// ulong4
+
+#pragma rs reduce(sum_ulong4) accumulator(sum_ulong4_accum)
+
+static void sum_ulong4_accum(ulong4 *accum, ulong4 in) { *accum += in; }
+
+#pragma rs reduce(min_ulong4) \
+  initializer(min_ulong4_init) accumulator(min_ulong4_accum)
+
+static void min_ulong4_init(ulong4 *accum) {
+  accum->x = 18446744073709551615UL;
+  accum->y = 18446744073709551615UL;
+  accum->z = 18446744073709551615UL;
+  accum->w = 18446744073709551615UL;
+}
+
+static void min_ulong4_accum(ulong4 *accum, ulong4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_ulong4) \
+  initializer(max_ulong4_init) accumulator(max_ulong4_accum)
+
+static void max_ulong4_init(ulong4 *accum) {
+  accum->x = 0;
+  accum->y = 0;
+  accum->z = 0;
+  accum->w = 0;
+}
+
+static void max_ulong4_accum(ulong4 *accum, ulong4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_ulong4) \
+  initializer(ffzInit) accumulator(ffz_ulong4_accum) combiner(ffzCombine)
+
+static void ffz_ulong4_accum(int *accum, ulong4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// half
+
+#pragma rs reduce(min_half) \
+  initializer(min_half_init) accumulator(min_half_accum)
+
+static void min_half_init(half *accum) {
+  *accum = (half) posinf();
+}
+
+static void min_half_accum(half *accum, half in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_half) \
+  initializer(max_half_init) accumulator(max_half_accum)
+
+static void max_half_init(half *accum) {
+  *accum = (half) neginf();
+}
+
+static void max_half_accum(half *accum, half in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_half) \
+  initializer(ffzInit) accumulator(ffz_half_accum) combiner(ffzCombine)
+
+static void ffz_half_accum(int *accum, half in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// half2
+
+#pragma rs reduce(min_half2) \
+  initializer(min_half2_init) accumulator(min_half2_accum)
+
+static void min_half2_init(half2 *accum) {
+  accum->x = (half) posinf();
+  accum->y = (half) posinf();
+}
+
+static void min_half2_accum(half2 *accum, half2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_half2) \
+  initializer(max_half2_init) accumulator(max_half2_accum)
+
+static void max_half2_init(half2 *accum) {
+  accum->x = (half) neginf();
+  accum->y = (half) neginf();
+}
+
+static void max_half2_accum(half2 *accum, half2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_half2) \
+  initializer(ffzInit) accumulator(ffz_half2_accum) combiner(ffzCombine)
+
+static void ffz_half2_accum(int *accum, half2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// half3
+
+#pragma rs reduce(min_half3) \
+  initializer(min_half3_init) accumulator(min_half3_accum)
+
+static void min_half3_init(half3 *accum) {
+  accum->x = (half) posinf();
+  accum->y = (half) posinf();
+  accum->z = (half) posinf();
+}
+
+static void min_half3_accum(half3 *accum, half3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_half3) \
+  initializer(max_half3_init) accumulator(max_half3_accum)
+
+static void max_half3_init(half3 *accum) {
+  accum->x = (half) neginf();
+  accum->y = (half) neginf();
+  accum->z = (half) neginf();
+}
+
+static void max_half3_accum(half3 *accum, half3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_half3) \
+  initializer(ffzInit) accumulator(ffz_half3_accum) combiner(ffzCombine)
+
+static void ffz_half3_accum(int *accum, half3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// half4
+
+#pragma rs reduce(min_half4) \
+  initializer(min_half4_init) accumulator(min_half4_accum)
+
+static void min_half4_init(half4 *accum) {
+  accum->x = (half) posinf();
+  accum->y = (half) posinf();
+  accum->z = (half) posinf();
+  accum->w = (half) posinf();
+}
+
+static void min_half4_accum(half4 *accum, half4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_half4) \
+  initializer(max_half4_init) accumulator(max_half4_accum)
+
+static void max_half4_init(half4 *accum) {
+  accum->x = (half) neginf();
+  accum->y = (half) neginf();
+  accum->z = (half) neginf();
+  accum->w = (half) neginf();
+}
+
+static void max_half4_accum(half4 *accum, half4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_half4) \
+  initializer(ffzInit) accumulator(ffz_half4_accum) combiner(ffzCombine)
+
+static void ffz_half4_accum(int *accum, half4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// float
+
+#pragma rs reduce(sum_float) accumulator(sum_float_accum)
+
+static void sum_float_accum(float *accum, float in) { *accum += in; }
+
+#pragma rs reduce(min_float) \
+  initializer(min_float_init) accumulator(min_float_accum)
+
+static void min_float_init(float *accum) {
+  *accum = posinf();
+}
+
+static void min_float_accum(float *accum, float in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_float) \
+  initializer(max_float_init) accumulator(max_float_accum)
+
+static void max_float_init(float *accum) {
+  *accum = neginf();
+}
+
+static void max_float_accum(float *accum, float in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_float) \
+  initializer(ffzInit) accumulator(ffz_float_accum) combiner(ffzCombine)
+
+static void ffz_float_accum(int *accum, float in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// float2
+
+#pragma rs reduce(sum_float2) accumulator(sum_float2_accum)
+
+static void sum_float2_accum(float2 *accum, float2 in) { *accum += in; }
+
+#pragma rs reduce(min_float2) \
+  initializer(min_float2_init) accumulator(min_float2_accum)
+
+static void min_float2_init(float2 *accum) {
+  accum->x = posinf();
+  accum->y = posinf();
+}
+
+static void min_float2_accum(float2 *accum, float2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_float2) \
+  initializer(max_float2_init) accumulator(max_float2_accum)
+
+static void max_float2_init(float2 *accum) {
+  accum->x = neginf();
+  accum->y = neginf();
+}
+
+static void max_float2_accum(float2 *accum, float2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_float2) \
+  initializer(ffzInit) accumulator(ffz_float2_accum) combiner(ffzCombine)
+
+static void ffz_float2_accum(int *accum, float2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// float3
+
+#pragma rs reduce(sum_float3) accumulator(sum_float3_accum)
+
+static void sum_float3_accum(float3 *accum, float3 in) { *accum += in; }
+
+#pragma rs reduce(min_float3) \
+  initializer(min_float3_init) accumulator(min_float3_accum)
+
+static void min_float3_init(float3 *accum) {
+  accum->x = posinf();
+  accum->y = posinf();
+  accum->z = posinf();
+}
+
+static void min_float3_accum(float3 *accum, float3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_float3) \
+  initializer(max_float3_init) accumulator(max_float3_accum)
+
+static void max_float3_init(float3 *accum) {
+  accum->x = neginf();
+  accum->y = neginf();
+  accum->z = neginf();
+}
+
+static void max_float3_accum(float3 *accum, float3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_float3) \
+  initializer(ffzInit) accumulator(ffz_float3_accum) combiner(ffzCombine)
+
+static void ffz_float3_accum(int *accum, float3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// float4
+
+#pragma rs reduce(sum_float4) accumulator(sum_float4_accum)
+
+static void sum_float4_accum(float4 *accum, float4 in) { *accum += in; }
+
+#pragma rs reduce(min_float4) \
+  initializer(min_float4_init) accumulator(min_float4_accum)
+
+static void min_float4_init(float4 *accum) {
+  accum->x = posinf();
+  accum->y = posinf();
+  accum->z = posinf();
+  accum->w = posinf();
+}
+
+static void min_float4_accum(float4 *accum, float4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_float4) \
+  initializer(max_float4_init) accumulator(max_float4_accum)
+
+static void max_float4_init(float4 *accum) {
+  accum->x = neginf();
+  accum->y = neginf();
+  accum->z = neginf();
+  accum->w = neginf();
+}
+
+static void max_float4_accum(float4 *accum, float4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_float4) \
+  initializer(ffzInit) accumulator(ffz_float4_accum) combiner(ffzCombine)
+
+static void ffz_float4_accum(int *accum, float4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// double
+
+#pragma rs reduce(sum_double) accumulator(sum_double_accum)
+
+static void sum_double_accum(double *accum, double in) { *accum += in; }
+
+#pragma rs reduce(min_double) \
+  initializer(min_double_init) accumulator(min_double_accum)
+
+static void min_double_init(double *accum) {
+  *accum = (double) posinf();
+}
+
+static void min_double_accum(double *accum, double in) {
+  if (in < *accum) *accum = in;
+}
+
+#pragma rs reduce(max_double) \
+  initializer(max_double_init) accumulator(max_double_accum)
+
+static void max_double_init(double *accum) {
+  *accum = (double) neginf();
+}
+
+static void max_double_accum(double *accum, double in) {
+  if (in > *accum) *accum = in;
+}
+
+#pragma rs reduce(ffz_double) \
+  initializer(ffzInit) accumulator(ffz_double_accum) combiner(ffzCombine)
+
+static void ffz_double_accum(int *accum, double in, int x /* special arg */) {
+  if ((in == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// double2
+
+#pragma rs reduce(sum_double2) accumulator(sum_double2_accum)
+
+static void sum_double2_accum(double2 *accum, double2 in) { *accum += in; }
+
+#pragma rs reduce(min_double2) \
+  initializer(min_double2_init) accumulator(min_double2_accum)
+
+static void min_double2_init(double2 *accum) {
+  accum->x = (double) posinf();
+  accum->y = (double) posinf();
+}
+
+static void min_double2_accum(double2 *accum, double2 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(max_double2) \
+  initializer(max_double2_init) accumulator(max_double2_accum)
+
+static void max_double2_init(double2 *accum) {
+  accum->x = (double) neginf();
+  accum->y = (double) neginf();
+}
+
+static void max_double2_accum(double2 *accum, double2 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+}
+
+#pragma rs reduce(ffz_double2) \
+  initializer(ffzInit) accumulator(ffz_double2_accum) combiner(ffzCombine)
+
+static void ffz_double2_accum(int *accum, double2 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// double3
+
+#pragma rs reduce(sum_double3) accumulator(sum_double3_accum)
+
+static void sum_double3_accum(double3 *accum, double3 in) { *accum += in; }
+
+#pragma rs reduce(min_double3) \
+  initializer(min_double3_init) accumulator(min_double3_accum)
+
+static void min_double3_init(double3 *accum) {
+  accum->x = (double) posinf();
+  accum->y = (double) posinf();
+  accum->z = (double) posinf();
+}
+
+static void min_double3_accum(double3 *accum, double3 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(max_double3) \
+  initializer(max_double3_init) accumulator(max_double3_accum)
+
+static void max_double3_init(double3 *accum) {
+  accum->x = (double) neginf();
+  accum->y = (double) neginf();
+  accum->z = (double) neginf();
+}
+
+static void max_double3_accum(double3 *accum, double3 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+}
+
+#pragma rs reduce(ffz_double3) \
+  initializer(ffzInit) accumulator(ffz_double3_accum) combiner(ffzCombine)
+
+static void ffz_double3_accum(int *accum, double3 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
+
+/////////////////////////////////////////////////////////////////////////
+// double4
+
+#pragma rs reduce(sum_double4) accumulator(sum_double4_accum)
+
+static void sum_double4_accum(double4 *accum, double4 in) { *accum += in; }
+
+#pragma rs reduce(min_double4) \
+  initializer(min_double4_init) accumulator(min_double4_accum)
+
+static void min_double4_init(double4 *accum) {
+  accum->x = (double) posinf();
+  accum->y = (double) posinf();
+  accum->z = (double) posinf();
+  accum->w = (double) posinf();
+}
+
+static void min_double4_accum(double4 *accum, double4 in) {
+  if (in.x < accum->x) accum->x = in.x;
+  if (in.y < accum->y) accum->y = in.y;
+  if (in.z < accum->z) accum->z = in.z;
+  if (in.w < accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(max_double4) \
+  initializer(max_double4_init) accumulator(max_double4_accum)
+
+static void max_double4_init(double4 *accum) {
+  accum->x = (double) neginf();
+  accum->y = (double) neginf();
+  accum->z = (double) neginf();
+  accum->w = (double) neginf();
+}
+
+static void max_double4_accum(double4 *accum, double4 in) {
+  if (in.x > accum->x) accum->x = in.x;
+  if (in.y > accum->y) accum->y = in.y;
+  if (in.z > accum->z) accum->z = in.z;
+  if (in.w > accum->w) accum->w = in.w;
+}
+
+#pragma rs reduce(ffz_double4) \
+  initializer(ffzInit) accumulator(ffz_double4_accum) combiner(ffzCombine)
+
+static void ffz_double4_accum(int *accum, double4 in, int x /* special arg */) {
+  if ((in.x == 0) && (in.y == 0) && (in.z == 0) && (in.w == 0) && ((*accum < 0) || (x < *accum)))
+    *accum = x;
+}
@@ -0,0 +1,20 @@
+#include "shared.rsh"
+
+// Reductions over 3-vector inputs.
+//
+// A 3-vector cell of an Allocation occupies the storage of a 4-vector;
+// the fourth (padding) lane is never passed to the accumulator, so its
+// contents must not affect any result.  Packed Java arrays (three
+// components per cell) are only valid for an Allocation with auto
+// padding enabled; the reflected array entry points do this themselves.
+
+#pragma rs reduce(sumInt3) accumulator(sumInt3Accum)
+
+static void sumInt3Accum(int3 *accum, int3 in) { *accum += in; }
+
+#pragma rs reduce(sumUchar3) \
+  accumulator(sumUchar3Accum) combiner(sumUint3Combine)
+
+static void sumUchar3Accum(uint3 *accum, uchar3 in) { *accum += convert_uint3(in); }
+
+static void sumUint3Combine(uint3 *accum, const uint3 *other) { *accum += *other; }
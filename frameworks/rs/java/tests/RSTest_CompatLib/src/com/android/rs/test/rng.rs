@@ -0,0 +1,24 @@
+#include "shared.rsh"
+#include "rng.rsh"
+
+uint32_t gSeed;
+
+#define RANGE 1000
+#define STREAM_LEN 3
+
+uint32_t RS_KERNEL drawUint(uint32_t x) { return rngUint(gSeed, x); }
+
+uint32_t RS_KERNEL drawRange(uint32_t x) { return rngRange(gSeed, x, RANGE); }
+
+float RS_KERNEL drawFloat(uint32_t x) { return rngFloat(gSeed, x); }
+
+// Cell x gets the last of STREAM_LEN values drawn from a stream that
+// starts at counter x * STREAM_LEN.
+uint32_t RS_KERNEL drawStream(uint32_t x) {
+  RngStream s;
+  rngStreamInit(&s, gSeed, x * STREAM_LEN);
+  uint32_t v = 0;
+  for (int i = 0; i < STREAM_LEN; ++i)
+    v = rngNextRange(&s, RANGE);
+  return v;
+}
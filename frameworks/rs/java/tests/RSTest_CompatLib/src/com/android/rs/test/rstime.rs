@@ -1,5 +1,41 @@
 #include "shared.rsh"
 
+rs_allocation gStamps;
+static int64_t gStampStartNanos;
+
+void stamp_start() {
+    gStampStartNanos = rsUptimeNanos();
+}
+
+// rsUptimeNanos is usable from kernels, e.g. to time sections of an
+// accumulator during development.
+int64_t RS_KERNEL stamp(uint32_t x) {
+    return rsUptimeNanos();
+}
+
+static bool uptime_test() {
+    bool failed = false;
+
+    int64_t nanos = rsUptimeNanos();
+    int64_t millis = rsUptimeMillis();
+    int64_t nanosAfter = rsUptimeNanos();
+
+    // Both clocks count from the same origin.
+    _RS_ASSERT(nanos <= nanosAfter);
+    _RS_ASSERT(nanos / 1000000 <= millis);
+    _RS_ASSERT(millis <= nanosAfter / 1000000);
+
+    // Every cell was stamped after stamp_start() and before now.
+    uint32_t dimX = rsAllocationGetDimX(gStamps);
+    for (uint32_t i = 0; i < dimX; i++) {
+        int64_t t = rsGetElementAt_long(gStamps, i);
+        _RS_ASSERT(t >= gStampStartNanos);
+        _RS_ASSERT(t <= nanos);
+    }
+
+    return failed;
+}
+
 static bool basic_test(uint32_t index) {
     bool failed = false;
 
@@ -39,6 +75,7 @@ static bool basic_test(uint32_t index) {
 void test_rstime(uint32_t index, int test_num) {
     bool failed = false;
     failed |= basic_test(index);
+    failed |= uptime_test();
 
     if (failed) {
         rsSendToClientBlocking(RS_MSG_TEST_FAILED);
@@ -0,0 +1,87 @@
+#include "shared.rsh"
+#include "saturating.rsh"
+
+static bool scalar_test() {
+  bool failed = false;
+
+  // long, in range
+  _RS_ASSERT(addSat(5L, -7L) == -2L);
+  _RS_ASSERT(subSat(5L, 7L) == -2L);
+  _RS_ASSERT(mulSat(-3L, 7L) == -21L);
+  _RS_ASSERT(mulSat(SAT_LONG_MIN, 1L) == SAT_LONG_MIN);
+  _RS_ASSERT(mulSat(SAT_LONG_MAX, -1L) == -SAT_LONG_MAX);
+
+  // long, saturating
+  _RS_ASSERT(addSat(SAT_LONG_MAX, 1L) == SAT_LONG_MAX);
+  _RS_ASSERT(addSat(SAT_LONG_MIN, -1L) == SAT_LONG_MIN);
+  _RS_ASSERT(subSat(SAT_LONG_MIN, 1L) == SAT_LONG_MIN);
+  _RS_ASSERT(subSat(0L, SAT_LONG_MIN) == SAT_LONG_MAX);
+  _RS_ASSERT(mulSat(SAT_LONG_MIN, -1L) == SAT_LONG_MAX);
+  _RS_ASSERT(mulSat(1L << 32, 1L << 31) == SAT_LONG_MAX);
+  _RS_ASSERT(mulSat(-(1L << 32), -(1L << 31)) == SAT_LONG_MAX);
+  _RS_ASSERT(mulSat(1L << 32, -(1L << 31)) == SAT_LONG_MIN);  // exact, not saturated
+  _RS_ASSERT(mulSat(-(1L << 32), 1L << 32) == SAT_LONG_MIN);
+  _RS_ASSERT(mulSat(1L << 32, -(1L << 32)) == SAT_LONG_MIN);
+
+  // ulong
+  _RS_ASSERT(addSat(5UL, 7UL) == 12UL);
+  _RS_ASSERT(addSat(SAT_ULONG_MAX, 1UL) == SAT_ULONG_MAX);
+  _RS_ASSERT(subSat(5UL, 7UL) == 0UL);
+  _RS_ASSERT(subSat(7UL, 5UL) == 2UL);
+  _RS_ASSERT(mulSat(1UL << 32, 1UL << 31) == 1UL << 63);
+  _RS_ASSERT(mulSat(1UL << 32, 1UL << 32) == SAT_ULONG_MAX);
+  _RS_ASSERT(mulSat(SAT_ULONG_MAX, 0UL) == 0UL);
+
+  return failed;
+}
+
+static bool vector_test() {
+  bool failed = false;
+
+  long4 a = { SAT_LONG_MAX, SAT_LONG_MIN, 3L, -4L };
+  long4 b = { 1L, -1L, 4L, 5L };
+  long4 sum = addSat(a, b);
+  _RS_ASSERT(sum.x == SAT_LONG_MAX);
+  _RS_ASSERT(sum.y == SAT_LONG_MIN);
+  _RS_ASSERT(sum.z == 7L);
+  _RS_ASSERT(sum.w == 1L);
+
+  long3 diff = subSat(a.xyz, -b.xyz);
+  _RS_ASSERT(diff.x == SAT_LONG_MAX);
+  _RS_ASSERT(diff.y == SAT_LONG_MIN);
+  _RS_ASSERT(diff.z == 7L);
+
+  long2 prod = mulSat(a.xy, b.xy + 1L);
+  _RS_ASSERT(prod.x == SAT_LONG_MAX);
+  _RS_ASSERT(prod.y == 0L);
+
+  ulong4 ua = { SAT_ULONG_MAX, 2UL, 3UL, 1UL << 32 };
+  ulong4 ub = { 1UL, 5UL, 4UL, 1UL << 32 };
+  ulong4 usum = addSat(ua, ub);
+  _RS_ASSERT(usum.x == SAT_ULONG_MAX);
+  _RS_ASSERT(usum.y == 7UL);
+  ulong4 udiff = subSat(ua, ub);
+  _RS_ASSERT(udiff.y == 0UL);
+  _RS_ASSERT(udiff.x == SAT_ULONG_MAX - 1UL);
+  ulong4 uprod = mulSat(ua, ub);
+  _RS_ASSERT(uprod.x == SAT_ULONG_MAX);
+  _RS_ASSERT(uprod.z == 12UL);
+  _RS_ASSERT(uprod.w == SAT_ULONG_MAX);
+
+  return failed;
+}
+
+void saturating_test() {
+  bool failed = false;
+  failed |= scalar_test();
+  failed |= vector_test();
+
+  if (failed) {
+    rsSendToClientBlocking(RS_MSG_TEST_FAILED);
+    rsDebug("saturating_test FAILED", -1);
+  }
+  else {
+    rsSendToClientBlocking(RS_MSG_TEST_PASSED);
+    rsDebug("saturating_test PASSED", 0);
+  }
+}